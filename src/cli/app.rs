@@ -1,10 +1,31 @@
+use crate::cli::report::OutputFormat;
+use crate::config::Paths;
 use clap::Parser;
 
+/// Exit code when the database directory could not be loaded
+pub const EXIT_DATABASE_ERROR: i32 = 3;
+
+/// Exit code when an input image could not be loaded or processed
+pub const EXIT_IMAGE_ERROR: i32 = 4;
+
+/// Exit code when the input was processed but no face was detected in any image
+pub const EXIT_NO_FACES: i32 = 5;
+
 /// A simple facial recognition CLI tool
 #[derive(Parser, Debug)]
-#[clap(author, version, about, long_about = None)]
+#[clap(
+    author,
+    version,
+    about,
+    long_about = None,
+    after_help = "Exit codes:\n  \
+                  0  success\n  \
+                  3  the database could not be loaded\n  \
+                  4  an input image could not be loaded or processed\n  \
+                  5  no faces were detected in any input image"
+)]
 pub struct Cli {
-    /// Path to the input image
+    /// Path to the input image, or a directory of images to process in one batch
     #[clap(short, long, value_parser)]
     pub input: String,
 
@@ -13,6 +34,37 @@ pub struct Cli {
     pub output: Option<String>,
 
     /// Path to the database directory containing reference images
-    #[clap(short = 'd', long, value_parser, default_value = "database")]
-    pub database: String,
+    /// [default: $FR_DATA_DIR, or `database` if unset]
+    #[clap(short = 'd', long, value_parser)]
+    pub database: Option<String>,
+
+    /// Print the detections as an ASCII box overlay (text format only)
+    #[clap(long)]
+    pub ascii: bool,
+
+    /// Output format; `json` always processes the input and includes the detections
+    #[clap(long, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+
+    /// Suppress the informational text output
+    #[clap(short, long)]
+    pub quiet: bool,
+
+    /// Write a thumbnail of every reference photo to the database's `thumbs/` directory
+    #[clap(long)]
+    pub thumbnails: bool,
+
+    /// Never show a progress bar for directory inputs (it is only shown on a terminal anyway)
+    #[clap(long)]
+    pub no_progress: bool,
+}
+
+impl Cli {
+    /// Resolves the data paths, preferring `--database` over `FR_DATA_DIR`
+    pub fn paths(&self) -> Paths {
+        match &self.database {
+            Some(database) => Paths::new(database),
+            None => Paths::from_env(),
+        }
+    }
 }
@@ -1,7 +1,10 @@
+pub use crate::config::THUMBNAIL_DIR;
+use crate::processors::image_loader::is_supported_image;
 use std::fs;
 use std::path::Path;
 
 /// Represents a person in the database
+#[derive(serde::Serialize)]
 pub struct Person {
     pub name: String,
     pub image_path: String,
@@ -29,26 +32,115 @@ pub fn load_database(database_path: &str) -> Result<Vec<Person>, Box<dyn std::er
         let entry = entry?;
         let path = entry.path();
 
-        // Only process files with .jpg extension
-        if path.is_file() && path.extension().is_some_and(|ext| ext == "jpg") {
+        // Only process files whose content is a supported image type, whatever their extension
+        if path.is_file() && is_supported_image(&path.to_string_lossy()) {
             if let Some(file_name) = path.file_stem() {
                 let name = file_name.to_string_lossy().to_string();
                 let image_path = path.to_string_lossy().to_string();
 
                 database.push(Person { name, image_path });
             }
+        } else if path.is_file() {
+            log::debug!("Skipping {}: not a supported image", path.display());
         }
     }
 
+    log::debug!("Loaded {} persons from {}", database.len(), database_path);
+
     Ok(database)
 }
 
+/// Default length of the longest side of generated thumbnails, in pixels
+pub const DEFAULT_THUMBNAIL_SIZE: u32 = 128;
+
+/// Results of generating thumbnails for a database
+#[derive(Debug, Default)]
+pub struct ThumbnailResults {
+    /// Paths of the written thumbnails, in the order of the database
+    pub thumbnails: Vec<String>,
+
+    /// Reference photo path and error message of each thumbnail that could not be generated
+    pub errors: Vec<(String, String)>,
+}
+
+/// Generates a thumbnail of each person's reference photo
+///
+/// Thumbnails are written as PNG files into the `thumbs/` subdirectory of the
+/// database, which `load_database` ignores. Each is named after the full file
+/// name of its reference photo (`jane.jpg` becomes `jane.jpg.png`), so photos
+/// that only differ in their extension do not overwrite each other. A photo
+/// that cannot be loaded or saved is recorded in the errors and skipped.
+///
+/// # Arguments
+///
+/// * `database_path` - Path to the directory containing reference images
+/// * `database` - The persons to generate thumbnails for
+/// * `max_dim` - Maximum length of the longest side of each thumbnail, in pixels
+///
+/// # Returns
+///
+/// * `ThumbnailResults` - The written thumbnails and the per-person errors, or an
+///   error if the thumbnail directory could not be created
+pub fn generate_thumbnails(
+    database_path: &str,
+    database: &[Person],
+    max_dim: u32,
+) -> Result<ThumbnailResults, Box<dyn std::error::Error>> {
+    let thumbs_dir = crate::config::Paths::new(database_path).thumbnails_dir();
+    fs::create_dir_all(&thumbs_dir)?;
+
+    let mut results = ThumbnailResults::default();
+    for person in database {
+        let file_name = Path::new(&person.image_path)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| person.name.clone());
+        let thumb_path = thumbs_dir.join(format!("{}.png", file_name));
+
+        let written =
+            crate::processors::image_loader::load_image(&person.image_path).and_then(|image| {
+                crate::processors::thumbnail::make_thumbnail(&image, max_dim)
+                    .save(&thumb_path)
+                    .map_err(Into::into)
+            });
+        match written {
+            Ok(()) => results
+                .thumbnails
+                .push(thumb_path.to_string_lossy().to_string()),
+            Err(e) => results
+                .errors
+                .push((person.image_path.clone(), e.to_string())),
+        }
+    }
+
+    log::debug!(
+        "Generated {} thumbnails in {} ({} failed)",
+        results.thumbnails.len(),
+        thumbs_dir.display(),
+        results.errors.len()
+    );
+
+    Ok(results)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::fs::File;
     use std::io::Write;
 
+    /// Writes a small, valid JPEG image to the given path
+    fn write_jpeg(path: &Path) {
+        write_image(path, image::ImageFormat::Jpeg);
+    }
+
+    /// Writes a small, valid image in the given format to the given path
+    fn write_image(path: &Path, format: image::ImageFormat) {
+        image::RgbImage::new(4, 4)
+            .save_with_format(path, format)
+            .expect("Failed to write test image");
+    }
+
     #[test]
     fn test_load_database() {
         // Create a temporary directory for testing
@@ -56,17 +148,8 @@ mod tests {
         let db_path = temp_dir.path().to_str().unwrap();
 
         // Create some test images
-        let file1_path = temp_dir.path().join("person1.jpg");
-        let mut file1 = File::create(&file1_path).expect("Failed to create test file");
-        file1
-            .write_all(b"fake image data")
-            .expect("Failed to write to test file");
-
-        let file2_path = temp_dir.path().join("person2.jpg");
-        let mut file2 = File::create(&file2_path).expect("Failed to create test file");
-        file2
-            .write_all(b"fake image data")
-            .expect("Failed to write to test file");
+        write_jpeg(&temp_dir.path().join("person1.jpg"));
+        write_jpeg(&temp_dir.path().join("person2.jpg"));
 
         // Load the database
         let database = load_database(db_path).expect("Failed to load database");
@@ -85,4 +168,110 @@ mod tests {
         let result = load_database("/nonexistent/path");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_load_database_rejects_mislabeled_file() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let db_path = temp_dir.path().to_str().unwrap();
+
+        // A PDF document with a .jpg extension
+        let mut file =
+            File::create(temp_dir.path().join("document.jpg")).expect("Failed to create test file");
+        file.write_all(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n1 0 obj\n<<>>\nendobj\n")
+            .expect("Failed to write to test file");
+
+        let database = load_database(db_path).expect("Failed to load database");
+        assert!(database.is_empty());
+    }
+
+    #[test]
+    fn test_load_database_accepts_extensionless_jpeg() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let db_path = temp_dir.path().to_str().unwrap();
+
+        write_jpeg(&temp_dir.path().join("person3"));
+
+        let database = load_database(db_path).expect("Failed to load database");
+        assert_eq!(database.len(), 1);
+        assert_eq!(database[0].name, "person3");
+    }
+
+    #[test]
+    fn test_load_database_mixed_extensions() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let db_path = temp_dir.path().to_str().unwrap();
+
+        write_jpeg(&temp_dir.path().join("upper.JPG"));
+        write_jpeg(&temp_dir.path().join("long.jpeg"));
+        write_image(
+            &temp_dir.path().join("portable.png"),
+            image::ImageFormat::Png,
+        );
+
+        let mut notes =
+            File::create(temp_dir.path().join("notes.txt")).expect("Failed to create test file");
+        notes
+            .write_all(b"not a photo")
+            .expect("Failed to write to test file");
+
+        let database = load_database(db_path).expect("Failed to load database");
+        let mut names: Vec<&str> = database.iter().map(|p| p.name.as_str()).collect();
+        names.sort_unstable();
+        assert_eq!(names, vec!["long", "portable", "upper"]);
+    }
+
+    #[test]
+    fn test_generate_thumbnails() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let db_path = temp_dir.path().to_str().unwrap();
+        image::RgbImage::new(400, 200)
+            .save(temp_dir.path().join("wide.png"))
+            .expect("Failed to write test image");
+        write_jpeg(&temp_dir.path().join("small.jpg"));
+
+        let database = load_database(db_path).expect("Failed to load database");
+        let results = generate_thumbnails(db_path, &database, 100).unwrap();
+        assert_eq!(results.thumbnails.len(), 2);
+        assert!(results.errors.is_empty());
+
+        for (person, thumb_path) in database.iter().zip(&results.thumbnails) {
+            let file_name = Path::new(&person.image_path).file_name().unwrap();
+            assert!(thumb_path.ends_with(&format!("{}.png", file_name.to_string_lossy())));
+            let dims = image::image_dimensions(thumb_path).unwrap();
+            let expected = if person.name == "wide" {
+                (100, 50)
+            } else {
+                (4, 4)
+            };
+            assert_eq!(dims, expected);
+        }
+
+        // Thumbnails are not picked up as persons
+        assert_eq!(load_database(db_path).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_generate_thumbnails_keeps_going_and_names_uniquely() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let db_path = temp_dir.path().to_str().unwrap();
+        write_jpeg(&temp_dir.path().join("jane.jpg"));
+        write_image(&temp_dir.path().join("jane.png"), image::ImageFormat::Png);
+
+        // A truncated PNG is recognized as an image but fails to decode
+        let mut png = Vec::new();
+        image::DynamicImage::new_rgb8(64, 64)
+            .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+            .unwrap();
+        fs::write(temp_dir.path().join("broken.png"), &png[..png.len() / 2]).unwrap();
+
+        let mut database = load_database(db_path).expect("Failed to load database");
+        database.sort_by(|a, b| a.image_path.cmp(&b.image_path));
+        let results = generate_thumbnails(db_path, &database, 100).unwrap();
+
+        assert_eq!(results.errors.len(), 1);
+        assert!(results.errors[0].0.ends_with("broken.png"));
+        assert_eq!(results.thumbnails.len(), 2);
+        assert!(results.thumbnails[0].ends_with("jane.jpg.png"));
+        assert!(results.thumbnails[1].ends_with("jane.png.png"));
+    }
 }
@@ -0,0 +1,198 @@
+use crate::cli::database::Person;
+use crate::models::detection::Detection;
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fmt::Write;
+
+/// Output format of the CLI
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text
+    #[default]
+    Text,
+
+    /// A single JSON document, for scripts and CI
+    Json,
+}
+
+/// Everything the CLI reports about a run
+#[derive(Serialize)]
+pub struct Report {
+    /// Path of the input image
+    pub input: String,
+
+    /// Path of the annotated output image, if one was requested
+    pub output: Option<String>,
+
+    /// Path of the database directory
+    pub database: String,
+
+    /// The persons loaded from the database
+    pub persons: Vec<Person>,
+
+    /// The faces detected in the input image, if it was processed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detections: Option<Vec<Detection>>,
+
+    /// The faces detected in each image, when the input is a directory
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<ImageReport>>,
+
+    /// Paths of the generated thumbnails, if thumbnails were requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnails: Option<Vec<String>>,
+}
+
+/// The faces detected in one image of a directory
+#[derive(Serialize)]
+pub struct ImageReport {
+    /// Path of the image
+    pub path: String,
+
+    /// The faces detected in the image
+    pub detections: Vec<Detection>,
+}
+
+impl Report {
+    /// Renders the report in the given format
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The output format
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The rendered report, ending with a newline
+    /// * `Err(Box<dyn std::error::Error>)` - An error if the report could not be serialized
+    pub fn render(&self, format: OutputFormat) -> Result<String, Box<dyn std::error::Error>> {
+        match format {
+            OutputFormat::Text => Ok(self.render_text()),
+            OutputFormat::Json => Ok(serde_json::to_string_pretty(self)? + "\n"),
+        }
+    }
+
+    /// Renders the report as the CLI's human-readable text
+    fn render_text(&self) -> String {
+        let mut text = String::new();
+
+        // Writing to a String cannot fail
+        let _ = writeln!(text, "Input image path: {}", self.input);
+        if let Some(output) = &self.output {
+            let _ = writeln!(text, "Output image path: {}", output);
+        }
+        let _ = writeln!(text, "Database path: {}", self.database);
+
+        let _ = writeln!(text, "Loaded {} persons from database", self.persons.len());
+        for person in &self.persons {
+            let _ = writeln!(text, "  - {}", person.name);
+        }
+
+        if let Some(thumbnails) = &self.thumbnails {
+            let _ = writeln!(text, "Generated {} thumbnails", thumbnails.len());
+        }
+
+        if let (Some(output), Some(_)) = (&self.output, &self.detections) {
+            let _ = writeln!(text, "Annotated image saved to {}", output);
+        }
+
+        for image in self.images.iter().flatten() {
+            let _ = writeln!(
+                text,
+                "{}: {} faces detected",
+                image.path,
+                image.detections.len()
+            );
+        }
+
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::bounding_box::BoundingBox;
+
+    fn sample_report() -> Report {
+        Report {
+            input: "photo.jpg".to_string(),
+            output: None,
+            database: "database".to_string(),
+            persons: vec![Person {
+                name: "jane".to_string(),
+                image_path: "database/jane.jpg".to_string(),
+            }],
+            detections: Some(vec![Detection {
+                confidence: 0.5,
+                bounding_box: BoundingBox::new(1, 2, 3, 4),
+            }]),
+            images: None,
+            thumbnails: None,
+        }
+    }
+
+    #[test]
+    fn test_render_json() {
+        let json = sample_report().render(OutputFormat::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["input"], "photo.jpg");
+        assert!(value["output"].is_null());
+        assert_eq!(value["persons"][0]["name"], "jane");
+        assert_eq!(value["persons"][0]["image_path"], "database/jane.jpg");
+
+        let detections: Vec<Detection> =
+            serde_json::from_value(value["detections"].clone()).unwrap();
+        assert_eq!(detections, sample_report().detections.unwrap());
+    }
+
+    #[test]
+    fn test_render_text() {
+        let text = sample_report().render(OutputFormat::Text).unwrap();
+        assert_eq!(
+            text,
+            "Input image path: photo.jpg\n\
+             Database path: database\n\
+             Loaded 1 persons from database\n  \
+             - jane\n"
+        );
+    }
+
+    #[test]
+    fn test_render_thumbnails() {
+        let report = Report {
+            thumbnails: Some(vec!["database/thumbs/jane.jpg.png".to_string()]),
+            ..sample_report()
+        };
+
+        let text = report.render(OutputFormat::Text).unwrap();
+        assert!(text.ends_with("  - jane\nGenerated 1 thumbnails\n"));
+
+        let json = report.render(OutputFormat::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["thumbnails"][0], "database/thumbs/jane.jpg.png");
+        let json = sample_report().render(OutputFormat::Json).unwrap();
+        assert!(!json.contains("thumbnails"));
+    }
+
+    #[test]
+    fn test_render_directory_images() {
+        let report = Report {
+            detections: None,
+            images: Some(vec![ImageReport {
+                path: "photos/a.jpg".to_string(),
+                detections: sample_report().detections.unwrap(),
+            }]),
+            ..sample_report()
+        };
+
+        let text = report.render(OutputFormat::Text).unwrap();
+        assert!(text.ends_with("photos/a.jpg: 1 faces detected\n"));
+
+        let json = report.render(OutputFormat::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert!(value.get("detections").is_none());
+        assert_eq!(value["images"][0]["path"], "photos/a.jpg");
+        assert_eq!(value["images"][0]["detections"][0]["confidence"], 0.5);
+    }
+}
@@ -0,0 +1,95 @@
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+/// Environment variable that overrides the data directory.
+pub const DATA_DIR_ENV: &str = "FR_DATA_DIR";
+
+/// Data directory used when neither a path nor `FR_DATA_DIR` is given.
+pub const DEFAULT_DATA_DIR: &str = "database";
+
+/// Name of the subdirectory of the data directory that holds generated thumbnails.
+pub const THUMBNAIL_DIR: &str = "thumbs";
+
+/// Filesystem locations used by the crate, rooted at one data directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paths {
+    data_dir: PathBuf,
+}
+
+impl Paths {
+    /// Creates paths rooted at an explicit data directory.
+    pub fn new(data_dir: impl Into<PathBuf>) -> Self {
+        Paths {
+            data_dir: data_dir.into(),
+        }
+    }
+
+    /// Resolves the data directory from `FR_DATA_DIR`, falling back to `database`.
+    ///
+    /// An empty `FR_DATA_DIR` counts as unset.
+    pub fn from_env() -> Self {
+        Paths::from_var(std::env::var_os(DATA_DIR_ENV))
+    }
+
+    /// Resolves the data directory from the value of `FR_DATA_DIR`, falling back to `database`.
+    ///
+    /// An empty value counts as unset.
+    pub fn from_var(value: Option<OsString>) -> Self {
+        match value {
+            Some(dir) if !dir.is_empty() => Paths::new(dir),
+            _ => Paths::default(),
+        }
+    }
+
+    /// The directory holding the reference photos of known persons.
+    pub fn data_dir(&self) -> &Path {
+        &self.data_dir
+    }
+
+    /// The directory generated thumbnails are written to.
+    pub fn thumbnails_dir(&self) -> PathBuf {
+        self.data_dir.join(THUMBNAIL_DIR)
+    }
+}
+
+impl Default for Paths {
+    fn default() -> Self {
+        Paths::new(DEFAULT_DATA_DIR)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paths_default() {
+        let paths = Paths::default();
+        assert_eq!(paths.data_dir(), Path::new("database"));
+        assert_eq!(paths.thumbnails_dir(), Path::new("database").join("thumbs"));
+        assert_eq!(Paths::new("/srv/faces").data_dir(), Path::new("/srv/faces"));
+    }
+
+    #[test]
+    fn test_paths_from_var_writes_under_override() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        image::RgbImage::new(40, 20)
+            .save(temp_dir.path().join("person.png"))
+            .expect("Failed to write test image");
+
+        // FR_DATA_DIR itself is covered by the CLI tests, which set it on a subprocess
+        let paths = Paths::from_var(Some(temp_dir.path().into()));
+        assert_eq!(paths.data_dir(), temp_dir.path());
+        assert_eq!(Paths::from_var(None), Paths::default());
+        assert_eq!(Paths::from_var(Some(OsString::new())), Paths::default());
+
+        let data_dir = paths.data_dir().to_str().unwrap();
+        let database = crate::cli::database::load_database(data_dir).unwrap();
+        let results = crate::cli::database::generate_thumbnails(data_dir, &database, 10)
+            .expect("Failed to generate thumbnails");
+
+        assert_eq!(results.thumbnails.len(), 1);
+        assert!(Path::new(&results.thumbnails[0]).starts_with(paths.thumbnails_dir()));
+        assert!(paths.thumbnails_dir().join("person.png.png").exists());
+    }
+}
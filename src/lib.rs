@@ -1,4 +1,5 @@
 pub mod cli;
+pub mod config;
 pub mod models;
 pub mod processors;
 pub mod utils;
@@ -15,16 +16,395 @@ pub mod utils;
 pub fn process_image(
     image_path: &str,
 ) -> Result<Vec<crate::models::detection::Detection>, Box<dyn std::error::Error>> {
-    let image = crate::processors::image_loader::load_image(image_path)?;
+    process_image_with_options(image_path, &ProcessOptions::default())
+}
+
+/// Async variant of `process_image` that runs on Tokio's blocking thread pool.
+///
+/// Loading and detection are blocking, CPU-bound work, so calling
+/// `process_image` directly from an async handler would stall the runtime.
+/// Must be called from within a Tokio runtime.
+///
+/// # Arguments
+///
+/// * `image_path` - The path to the image file.
+///
+/// # Returns
+///
+/// * `Result<Vec<crate::models::detection::Detection>, Box<dyn std::error::Error + Send + Sync>>` - A result containing a vector of detections or an error.
+pub async fn process_image_async(
+    image_path: String,
+) -> Result<Vec<crate::models::detection::Detection>, Box<dyn std::error::Error + Send + Sync>> {
+    // The boxed error of `process_image` is not `Send`, so carry its message across threads
+    tokio::task::spawn_blocking(move || process_image(&image_path).map_err(|e| e.to_string()))
+        .await?
+        .map_err(Into::into)
+}
+
+/// Public API function to detect faces in an encoded image held in memory.
+///
+/// Images larger than `DEFAULT_MAX_PIXELS` are rejected before decoding.
+///
+/// # Arguments
+///
+/// * `bytes` - The encoded image data (e.g. an HTTP upload).
+///
+/// # Returns
+///
+/// * `Result<Vec<crate::models::detection::Detection>, Box<dyn std::error::Error>>` - A result containing a vector of detections or an error.
+pub fn process_image_bytes(
+    bytes: &[u8],
+) -> Result<Vec<crate::models::detection::Detection>, Box<dyn std::error::Error>> {
+    let image = crate::processors::image_loader::load_image_from_bytes_bounded(
+        bytes,
+        crate::processors::image_loader::DEFAULT_MAX_PIXELS,
+    )?;
     let detections = crate::processors::face_detector::detect_faces(&image);
+    log::debug!(
+        "Detected {} faces in {}-byte image",
+        detections.len(),
+        bytes.len()
+    );
     Ok(detections)
 }
 
+/// Options for `process_image_with_options`.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessOptions {
+    /// Skin-pixel ratio threshold passed to the detector, or `None` for the default.
+    pub threshold: Option<f32>,
+
+    /// Path to save a copy of the image annotated with the detections, if any.
+    pub output_path: Option<String>,
+
+    /// Whether to stretch the image contrast before detection, which helps
+    /// with underexposed photos.
+    pub auto_contrast: bool,
+
+    /// Maximum number of detections to return, keeping the most confident.
+    pub max_detections: Option<usize>,
+
+    /// Largest accepted image in pixels, or `None` for
+    /// [`DEFAULT_MAX_PIXELS`](crate::processors::image_loader::DEFAULT_MAX_PIXELS).
+    pub max_pixels: Option<u64>,
+}
+
+/// Timing and size information collected while processing an image.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProcessingStats {
+    /// Time spent loading and decoding the image, in milliseconds.
+    pub load_ms: u64,
+
+    /// Time spent detecting faces, in milliseconds.
+    pub detect_ms: u64,
+
+    /// Dimensions of the loaded image as (width, height).
+    pub image_dims: (u32, u32),
+}
+
+/// Public API function to process an image and detect faces with custom options.
+///
+/// # Arguments
+///
+/// * `image_path` - A string slice that holds the path to the image file.
+/// * `options` - The options controlling detection.
+///
+/// # Returns
+///
+/// * `Result<Vec<crate::models::detection::Detection>, Box<dyn std::error::Error>>` - A result containing a vector of detections or an error.
+pub fn process_image_with_options(
+    image_path: &str,
+    options: &ProcessOptions,
+) -> Result<Vec<crate::models::detection::Detection>, Box<dyn std::error::Error>> {
+    let (detections, _) = process_image_with_stats(image_path, options)?;
+    Ok(detections)
+}
+
+/// Public API function to process an image and report how long each step took.
+///
+/// # Arguments
+///
+/// * `image_path` - A string slice that holds the path to the image file.
+///
+/// # Returns
+///
+/// * `Result<(Vec<crate::models::detection::Detection>, ProcessingStats), Box<dyn std::error::Error>>` - A result containing the detections and processing statistics, or an error.
+pub fn process_image_timed(
+    image_path: &str,
+) -> Result<(Vec<crate::models::detection::Detection>, ProcessingStats), Box<dyn std::error::Error>>
+{
+    process_image_with_stats(image_path, &ProcessOptions::default())
+}
+
+/// Options for `process_directory_with_options`.
+#[derive(Debug, Clone, Default)]
+pub struct DirectoryOptions {
+    /// Whether to also process images in subdirectories.
+    pub recursive: bool,
+}
+
+/// Path of an image together with the faces detected in it.
+pub type ImageDetections = (String, Vec<crate::models::detection::Detection>);
+
+/// Results of processing every image in a directory.
+#[derive(Debug, Default)]
+pub struct DirectoryResults {
+    /// Path and detections of each image that was processed successfully.
+    pub detections: Vec<ImageDetections>,
+
+    /// Path and error message of each image that could not be processed.
+    pub errors: Vec<(String, String)>,
+}
+
+/// Public API function to detect faces in every image of a directory.
+///
+/// Files are picked with
+/// [`is_supported_image`](crate::processors::image_loader::is_supported_image)
+/// and processed in path order. Images that fail to process are logged and
+/// skipped; use `process_directory_with_options` to get their errors.
+///
+/// # Arguments
+///
+/// * `dir` - A string slice that holds the path to the directory.
+///
+/// # Returns
+///
+/// * `Result<Vec<ImageDetections>, Box<dyn std::error::Error>>` - A result containing the path and detections of each processed image, or an error if the directory could not be read.
+pub fn process_directory(dir: &str) -> Result<Vec<ImageDetections>, Box<dyn std::error::Error>> {
+    let results = process_directory_with_options(dir, &DirectoryOptions::default())?;
+    for (path, error) in &results.errors {
+        log::warn!("Failed to process {}: {}", path, error);
+    }
+    Ok(results.detections)
+}
+
+/// Public API function to detect faces in every image of a directory with custom options.
+///
+/// # Arguments
+///
+/// * `dir` - A string slice that holds the path to the directory.
+/// * `options` - The options controlling which files are processed.
+///
+/// # Returns
+///
+/// * `Result<DirectoryResults, Box<dyn std::error::Error>>` - A result containing the detections and errors per image, or an error if the directory could not be read.
+pub fn process_directory_with_options(
+    dir: &str,
+    options: &DirectoryOptions,
+) -> Result<DirectoryResults, Box<dyn std::error::Error>> {
+    process_directory_with_progress(dir, options, &mut crate::utils::progress::NoProgress)
+}
+
+/// Public API function to detect faces in every image of a directory, reporting progress.
+///
+/// # Arguments
+///
+/// * `dir` - A string slice that holds the path to the directory.
+/// * `options` - The options controlling which files are processed.
+/// * `progress` - The sink notified once per processed image.
+///
+/// # Returns
+///
+/// * `Result<DirectoryResults, Box<dyn std::error::Error>>` - A result containing the detections and errors per image, or an error if the directory could not be read.
+pub fn process_directory_with_progress(
+    dir: &str,
+    options: &DirectoryOptions,
+    progress: &mut dyn crate::utils::progress::Progress,
+) -> Result<DirectoryResults, Box<dyn std::error::Error>> {
+    let mut results = DirectoryResults::default();
+    stream_directory(
+        dir,
+        options,
+        progress,
+        |path, detections| results.detections.push((path, detections)),
+        |path, error| results.errors.push((path, error)),
+    )?;
+
+    log::debug!(
+        "Processed {} images in {} ({} failed)",
+        results.detections.len() + results.errors.len(),
+        dir,
+        results.errors.len()
+    );
+    Ok(results)
+}
+
+/// Public API function to detect faces in every image of a directory, delivering results as they become available.
+///
+/// Unlike `process_directory_with_options`, nothing is collected: `sink` is
+/// called with the detections of each image right after it is processed, so
+/// callers can write them out incrementally (e.g. as JSON Lines). Images that
+/// fail to process are passed to `on_error` and do not stop the run.
+///
+/// # Arguments
+///
+/// * `dir` - A string slice that holds the path to the directory.
+/// * `options` - The options controlling which files are processed.
+/// * `sink` - Called with the path and detections of each processed image.
+/// * `on_error` - Called with the path and error message of each image that could not be processed.
+///
+/// # Returns
+///
+/// * `Result<(), Box<dyn std::error::Error>>` - An error if the directory could not be read.
+pub fn process_directory_streaming(
+    dir: &str,
+    options: &DirectoryOptions,
+    sink: impl FnMut(String, Vec<crate::models::detection::Detection>),
+    on_error: impl FnMut(String, String),
+) -> Result<(), Box<dyn std::error::Error>> {
+    stream_directory(
+        dir,
+        options,
+        &mut crate::utils::progress::NoProgress,
+        sink,
+        on_error,
+    )
+}
+
+/// Processes the images of a directory in path order, passing each result to a callback.
+fn stream_directory(
+    dir: &str,
+    options: &DirectoryOptions,
+    progress: &mut dyn crate::utils::progress::Progress,
+    mut sink: impl FnMut(String, Vec<crate::models::detection::Detection>),
+    mut on_error: impl FnMut(String, String),
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut paths = Vec::new();
+    collect_image_paths(std::path::Path::new(dir), options.recursive, &mut paths)?;
+    paths.sort();
+
+    progress.start(paths.len());
+    for path in paths {
+        let path = path.to_string_lossy().to_string();
+        match process_image(&path) {
+            Ok(detections) => sink(path.clone(), detections),
+            Err(e) => on_error(path.clone(), e.to_string()),
+        }
+        progress.item_done(&path);
+    }
+    progress.finish();
+    Ok(())
+}
+
+/// Collects the supported image files in `dir`.
+fn collect_image_paths(
+    dir: &std::path::Path,
+    recursive: bool,
+    paths: &mut Vec<std::path::PathBuf>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            if recursive {
+                collect_image_paths(&path, recursive, paths)?;
+            }
+        } else if crate::processors::image_loader::is_supported_image(&path.to_string_lossy()) {
+            paths.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Public API function to process an image with custom options and report statistics.
+///
+/// `ProcessingStats::image_dims` are the dimensions of the decoded, upright
+/// image the detections refer to, which differ from the stored dimensions of
+/// photos with an EXIF rotation.
+///
+/// # Arguments
+///
+/// * `image_path` - A string slice that holds the path to the image file.
+/// * `options` - The options controlling detection.
+///
+/// # Returns
+///
+/// * `Result<(Vec<crate::models::detection::Detection>, ProcessingStats), Box<dyn std::error::Error>>` - A result containing the detections and processing statistics, or an error.
+pub fn process_image_with_stats(
+    image_path: &str,
+    options: &ProcessOptions,
+) -> Result<(Vec<crate::models::detection::Detection>, ProcessingStats), Box<dyn std::error::Error>>
+{
+    let load_start = std::time::Instant::now();
+    let max_pixels = options
+        .max_pixels
+        .unwrap_or(crate::processors::image_loader::DEFAULT_MAX_PIXELS);
+    let image = crate::processors::image_loader::load_image_bounded(image_path, max_pixels)?;
+    let load_ms = load_start.elapsed().as_millis() as u64;
+    log::debug!(
+        "Loaded {} ({}x{}) in {} ms",
+        image_path,
+        image.width(),
+        image.height(),
+        load_ms
+    );
+
+    let threshold = options
+        .threshold
+        .unwrap_or(crate::processors::face_detector::DEFAULT_SKIN_RATIO_THRESHOLD);
+    let detect_start = std::time::Instant::now();
+    // Only the detector sees the adjusted image; annotations go on the original
+    let adjusted = options
+        .auto_contrast
+        .then(|| crate::processors::preprocess::auto_contrast(&image));
+    let detections = crate::processors::face_detector::detect_faces_with_threshold(
+        adjusted.as_ref().unwrap_or(&image),
+        threshold,
+    );
+    let detections = match options.max_detections {
+        Some(k) => crate::models::detection::top_k(detections, k),
+        None => detections,
+    };
+    let detect_ms = detect_start.elapsed().as_millis() as u64;
+    log::debug!(
+        "Detected {} faces in {} in {} ms",
+        detections.len(),
+        image_path,
+        detect_ms
+    );
+
+    if let Some(output_path) = &options.output_path {
+        crate::processors::annotate::draw_detections(&image, &detections).save(output_path)?;
+        log::debug!("Saved annotated image to {}", output_path);
+    }
+
+    let stats = ProcessingStats {
+        load_ms,
+        detect_ms,
+        image_dims: (image.width(), image.height()),
+    };
+    Ok((detections, stats))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Mutex;
     use tempfile::tempdir;
 
+    /// Logger that keeps every record's message so tests can inspect them.
+    struct CapturingLogger {
+        messages: Mutex<Vec<String>>,
+    }
+
+    impl log::Log for CapturingLogger {
+        fn enabled(&self, _metadata: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            self.messages
+                .lock()
+                .unwrap()
+                .push(format!("{} {}", record.level(), record.args()));
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: CapturingLogger = CapturingLogger {
+        messages: Mutex::new(Vec::new()),
+    };
+
     #[test]
     fn test_process_image_with_invalid_path() {
         let result = process_image("invalid_path.png");
@@ -56,4 +436,400 @@ mod tests {
         // Clean up
         dir.close().expect("Failed to clean up temporary directory");
     }
+
+    #[test]
+    fn test_process_image_with_threshold_option() {
+        let dir = tempdir().expect("Failed to create temporary directory");
+        let file_path = dir.path().join("skin.png");
+
+        // Skin-like square on a blue background
+        let mut img_buffer = image::RgbImage::new(200, 200);
+        for (x, y, pixel) in img_buffer.enumerate_pixels_mut() {
+            if (50..150).contains(&x) && (50..150).contains(&y) {
+                *pixel = image::Rgb([180, 140, 120]);
+            } else {
+                *pixel = image::Rgb([0, 0, 255]);
+            }
+        }
+        img_buffer
+            .save(&file_path)
+            .expect("Failed to save test image");
+        let path = file_path.to_str().unwrap();
+
+        let default = process_image(path).expect("Failed to process image");
+        let strict = process_image_with_options(
+            path,
+            &ProcessOptions {
+                threshold: Some(0.9),
+                ..Default::default()
+            },
+        )
+        .expect("Failed to process image");
+
+        assert!(strict.len() < default.len());
+    }
+
+    #[test]
+    fn test_process_image_saves_annotated_output() {
+        let dir = tempdir().expect("Failed to create temporary directory");
+        let input_path = dir.path().join("skin.png");
+        let output_path = dir.path().join("annotated.png");
+
+        // Skin-like square on a blue background
+        let mut img_buffer = image::RgbImage::new(200, 200);
+        for (x, y, pixel) in img_buffer.enumerate_pixels_mut() {
+            if (50..150).contains(&x) && (50..150).contains(&y) {
+                *pixel = image::Rgb([180, 140, 120]);
+            } else {
+                *pixel = image::Rgb([0, 0, 255]);
+            }
+        }
+        img_buffer
+            .save(&input_path)
+            .expect("Failed to save test image");
+
+        let options = ProcessOptions {
+            output_path: Some(output_path.to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+        let detections = process_image_with_options(input_path.to_str().unwrap(), &options)
+            .expect("Failed to process image");
+        assert!(!detections.is_empty());
+
+        let annotated = image::open(&output_path).expect("Annotated image was not saved");
+        assert_ne!(annotated.to_rgb8(), img_buffer);
+    }
+
+    #[test]
+    fn test_process_image_rejects_images_over_max_pixels() {
+        let dir = tempdir().expect("Failed to create temporary directory");
+        let file_path = dir.path().join("large.png");
+        image::RgbImage::from_pixel(120, 80, image::Rgb([180, 140, 120]))
+            .save(&file_path)
+            .expect("Failed to save test image");
+        let path = file_path.to_str().unwrap();
+
+        assert!(process_image(path).is_ok());
+        let result = process_image_with_options(
+            path,
+            &ProcessOptions {
+                max_pixels: Some(100 * 80),
+                ..Default::default()
+            },
+        );
+        assert!(result.is_err());
+    }
+
+    /// Writes a small skin-colored PNG to `path`
+    fn write_test_image(path: &std::path::Path) {
+        image::RgbImage::from_pixel(60, 40, image::Rgb([180, 140, 120]))
+            .save(path)
+            .expect("Failed to save test image");
+    }
+
+    #[test]
+    fn test_process_directory() {
+        let dir = tempdir().expect("Failed to create temporary directory");
+        write_test_image(&dir.path().join("a.png"));
+        write_test_image(&dir.path().join("b.png"));
+        // A JPEG signature followed by garbage: listed as an image, fails to decode
+        std::fs::write(
+            dir.path().join("broken.jpg"),
+            b"\xFF\xD8\xFF\xE0not an image",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("notes.txt"), b"ignored").unwrap();
+        std::fs::write(dir.path().join("notes.jpg"), b"ignored despite its name").unwrap();
+
+        let results =
+            process_directory(dir.path().to_str().unwrap()).expect("Failed to process directory");
+        let names: Vec<_> = results
+            .iter()
+            .map(|(path, _)| std::path::Path::new(path).file_name().unwrap().to_owned())
+            .collect();
+        assert_eq!(names, ["a.png", "b.png"]);
+
+        let results = process_directory_with_options(
+            dir.path().to_str().unwrap(),
+            &DirectoryOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(results.errors.len(), 1);
+        assert!(results.errors[0].0.ends_with("broken.jpg"));
+    }
+
+    #[test]
+    fn test_process_directory_recursive() {
+        let dir = tempdir().expect("Failed to create temporary directory");
+        let nested = dir.path().join("nested");
+        std::fs::create_dir(&nested).unwrap();
+        write_test_image(&dir.path().join("top.png"));
+        write_test_image(&nested.join("inner.png"));
+        let dir = dir.path().to_str().unwrap();
+
+        assert_eq!(process_directory(dir).unwrap().len(), 1);
+
+        let results =
+            process_directory_with_options(dir, &DirectoryOptions { recursive: true }).unwrap();
+        assert_eq!(results.detections.len(), 2);
+        assert!(results.errors.is_empty());
+    }
+
+    /// Progress sink that records every call
+    #[derive(Default)]
+    struct RecordingProgress {
+        total: Option<usize>,
+        items: Vec<String>,
+        finished: usize,
+    }
+
+    impl crate::utils::progress::Progress for RecordingProgress {
+        fn start(&mut self, total: usize) {
+            self.total = Some(total);
+        }
+
+        fn item_done(&mut self, item: &str) {
+            self.items.push(item.to_string());
+        }
+
+        fn finish(&mut self) {
+            self.finished += 1;
+        }
+    }
+
+    #[test]
+    fn test_process_directory_reports_progress_per_item() {
+        let dir = tempdir().expect("Failed to create temporary directory");
+        write_test_image(&dir.path().join("a.png"));
+        write_test_image(&dir.path().join("b.png"));
+        // A JPEG signature followed by garbage: listed as an image, fails to decode
+        std::fs::write(
+            dir.path().join("broken.jpg"),
+            b"\xFF\xD8\xFF\xE0not an image",
+        )
+        .unwrap();
+
+        let mut progress = RecordingProgress::default();
+        let results = process_directory_with_progress(
+            dir.path().to_str().unwrap(),
+            &DirectoryOptions::default(),
+            &mut progress,
+        )
+        .unwrap();
+
+        assert_eq!(progress.total, Some(3));
+        assert_eq!(progress.items.len(), 3);
+        assert_eq!(progress.finished, 1);
+        assert_eq!(results.detections.len() + results.errors.len(), 3);
+    }
+
+    #[test]
+    fn test_process_directory_with_tiny_image() {
+        let dir = tempdir().expect("Failed to create temporary directory");
+        write_test_image(&dir.path().join("a.png"));
+        image::RgbImage::from_pixel(16, 16, image::Rgb([180, 140, 120]))
+            .save(dir.path().join("tiny.png"))
+            .expect("Failed to save test image");
+
+        let results = process_directory_with_options(
+            dir.path().to_str().unwrap(),
+            &DirectoryOptions::default(),
+        )
+        .expect("Failed to process directory");
+        assert_eq!(results.detections.len(), 2);
+        assert!(results.errors.is_empty());
+    }
+
+    #[test]
+    fn test_process_directory_missing() {
+        assert!(process_directory("no_such_directory").is_err());
+    }
+
+    #[test]
+    fn test_process_directory_streaming() {
+        let dir = tempdir().expect("Failed to create temporary directory");
+        write_test_image(&dir.path().join("a.png"));
+        write_test_image(&dir.path().join("b.png"));
+        write_test_image(&dir.path().join("c.png"));
+        // A JPEG signature followed by garbage: listed as an image, fails to decode
+        std::fs::write(
+            dir.path().join("broken.jpg"),
+            b"\xFF\xD8\xFF\xE0not an image",
+        )
+        .unwrap();
+
+        let mut streamed = Vec::new();
+        let mut errors = Vec::new();
+        process_directory_streaming(
+            dir.path().to_str().unwrap(),
+            &DirectoryOptions::default(),
+            |path, detections| streamed.push((path, detections)),
+            |path, error| errors.push((path, error)),
+        )
+        .expect("Failed to process directory");
+
+        let names: Vec<_> = streamed
+            .iter()
+            .map(|(path, _)| std::path::Path::new(path).file_name().unwrap().to_owned())
+            .collect();
+        assert_eq!(names, ["a.png", "b.png", "c.png"]);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].0.ends_with("broken.jpg"));
+
+        assert!(process_directory_streaming(
+            "no_such_directory",
+            &DirectoryOptions::default(),
+            |_, _| {},
+            |_, _| {},
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_process_image_auto_contrast() {
+        let dir = tempdir().expect("Failed to create temporary directory");
+        let file_path = dir.path().join("dark.png");
+
+        // Underexposed skin-like square on a dark blue background
+        let mut img_buffer = image::RgbImage::new(200, 200);
+        for (x, y, pixel) in img_buffer.enumerate_pixels_mut() {
+            *pixel = if (50..150).contains(&x) && (50..150).contains(&y) {
+                image::Rgb([72, 56, 48])
+            } else {
+                image::Rgb([0, 0, 40])
+            };
+        }
+        img_buffer
+            .save(&file_path)
+            .expect("Failed to save test image");
+        let path = file_path.to_str().unwrap();
+
+        assert!(process_image(path).unwrap().is_empty());
+        let detections = process_image_with_options(
+            path,
+            &ProcessOptions {
+                auto_contrast: true,
+                ..Default::default()
+            },
+        )
+        .expect("Failed to process image");
+        assert!(!detections.is_empty());
+    }
+
+    #[test]
+    fn test_process_image_max_detections() {
+        let dir = tempdir().expect("Failed to create temporary directory");
+        let file_path = dir.path().join("skin.png");
+        write_test_image(&file_path);
+        let path = file_path.to_str().unwrap();
+
+        let all = process_image(path).expect("Failed to process image");
+        assert!(all.len() > 2);
+
+        let capped = process_image_with_options(
+            path,
+            &ProcessOptions {
+                max_detections: Some(2),
+                ..Default::default()
+            },
+        )
+        .expect("Failed to process image");
+        assert_eq!(capped, all[..2]);
+    }
+
+    #[test]
+    fn test_process_image_timed() {
+        let dir = tempdir().expect("Failed to create temporary directory");
+        let file_path = dir.path().join("timed.png");
+        image::RgbImage::from_pixel(120, 80, image::Rgb([180, 140, 120]))
+            .save(&file_path)
+            .expect("Failed to save test image");
+
+        let (detections, stats) =
+            process_image_timed(file_path.to_str().unwrap()).expect("Failed to process image");
+
+        assert_eq!(stats.image_dims, (120, 80));
+        assert_eq!(
+            detections.len(),
+            process_image(file_path.to_str().unwrap()).unwrap().len()
+        );
+        // Sub-millisecond steps round down to zero, so only check they are sane
+        assert!(stats.load_ms < 60_000);
+        assert!(stats.detect_ms < 60_000);
+
+        assert!(process_image_timed("invalid_path.png").is_err());
+    }
+
+    #[test]
+    fn test_process_image_bytes() {
+        // Skin-like square on a blue background, encoded as PNG in memory
+        let mut img_buffer = image::RgbImage::new(200, 200);
+        for (x, y, pixel) in img_buffer.enumerate_pixels_mut() {
+            if (50..150).contains(&x) && (50..150).contains(&y) {
+                *pixel = image::Rgb([180, 140, 120]);
+            } else {
+                *pixel = image::Rgb([0, 0, 255]);
+            }
+        }
+        let mut bytes = Vec::new();
+        image::DynamicImage::ImageRgb8(img_buffer)
+            .write_to(
+                &mut std::io::Cursor::new(&mut bytes),
+                image::ImageFormat::Png,
+            )
+            .expect("Failed to encode test image");
+
+        let detections = process_image_bytes(&bytes).expect("Failed to process image");
+        assert!(!detections.is_empty());
+
+        assert!(process_image_bytes(&[]).is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_process_image_async_concurrent() {
+        let dir = tempdir().expect("Failed to create temporary directory");
+
+        let mut paths = Vec::new();
+        for i in 0..4 {
+            let file_path = dir.path().join(format!("image_{}.png", i));
+            image::RgbImage::from_pixel(100, 100, image::Rgb([180, 140, 120]))
+                .save(&file_path)
+                .expect("Failed to save test image");
+            paths.push(file_path.to_str().unwrap().to_string());
+        }
+        paths.push("invalid_path.png".to_string());
+
+        let handles: Vec<_> = paths
+            .into_iter()
+            .map(|path| tokio::spawn(process_image_async(path)))
+            .collect();
+
+        let mut results = Vec::new();
+        for handle in handles {
+            results.push(handle.await.expect("Task panicked"));
+        }
+
+        assert!(results[..4].iter().all(|result| result.is_ok()));
+        assert!(results[4].is_err());
+    }
+
+    #[test]
+    fn test_process_image_logs_detection_count() {
+        // The logger can only be installed once per process
+        let _ = log::set_logger(&LOGGER);
+        log::set_max_level(log::LevelFilter::Debug);
+
+        let dir = tempdir().expect("Failed to create temporary directory");
+        let file_path = dir.path().join("logged.png");
+        image::RgbImage::from_pixel(50, 50, image::Rgb([0, 0, 255]))
+            .save(&file_path)
+            .expect("Failed to save test image");
+        let path = file_path.to_str().unwrap();
+
+        process_image(path).expect("Failed to process image");
+
+        let messages = LOGGER.messages.lock().unwrap();
+        let expected = format!("DEBUG Detected 0 faces in {}", path);
+        assert!(messages.iter().any(|m| m.starts_with(&expected)));
+    }
 }
@@ -1,40 +1,179 @@
-mod cli;
-mod models;
-mod processors;
-mod utils;
-
 use clap::Parser;
-use cli::app::Cli;
-use cli::database;
+use facial_recognition::cli::app::{Cli, EXIT_DATABASE_ERROR, EXIT_IMAGE_ERROR, EXIT_NO_FACES};
+use facial_recognition::cli::database;
+use facial_recognition::cli::report::{ImageReport, OutputFormat, Report};
+use facial_recognition::models::detection::Detection;
+use facial_recognition::processors::image_loader::{is_supported_image, supported_extensions};
+use facial_recognition::utils::ascii::ascii_overlay;
+use facial_recognition::utils::progress::{NoProgress, Progress};
+use facial_recognition::{
+    process_directory_with_progress, process_image_with_stats, DirectoryOptions, ProcessOptions,
+};
+use std::io::IsTerminal;
+
+/// Number of columns used for the ASCII overlay.
+const ASCII_COLS: u32 = 80;
+
+/// Maximum number of rows of the ASCII overlay, so very tall images stay readable.
+const ASCII_MAX_ROWS: u32 = 200;
 
 fn main() {
+    env_logger::init();
     let cli = Cli::parse();
 
-    println!("Input image path: {}", cli.input);
-    if let Some(output) = &cli.output {
-        println!("Output image path: {}", output);
+    // Load the database of known faces
+    let database_path = cli.paths().data_dir().to_string_lossy().to_string();
+    let persons = match database::load_database(&database_path) {
+        Ok(persons) => persons,
+        Err(e) => {
+            eprintln!("Error loading database: {}", e);
+            std::process::exit(EXIT_DATABASE_ERROR);
+        }
+    };
+
+    let thumbnails = cli
+        .thumbnails
+        .then(|| generate_thumbnails(&database_path, &persons));
+
+    let input_path = std::path::Path::new(&cli.input);
+    let is_directory = input_path.is_dir();
+    if input_path.is_file() && !is_supported_image(&cli.input) {
+        eprintln!(
+            "Unsupported image format: {} (supported: {})",
+            cli.input,
+            supported_extensions().join(", ")
+        );
+        std::process::exit(EXIT_IMAGE_ERROR);
     }
-    println!("Database path: {}", cli.database);
+    let ascii = cli.ascii && cli.format == OutputFormat::Text && !is_directory;
+    let (detections, image_dims, images, failed) = if is_directory {
+        let (images, failed) = detect_directory(&cli.input, !cli.no_progress);
+        (None, None, Some(images), failed)
+    } else {
+        let (detections, image_dims) = detect(&cli.input, cli.output.as_deref());
+        (Some(detections), Some(image_dims), None, false)
+    };
 
-    // Load the database of known faces
-    match database::load_database(&cli.database) {
-        Ok(database) => {
-            println!("Loaded {} persons from database", database.len());
+    let report = Report {
+        input: cli.input.clone(),
+        output: cli.output.clone(),
+        database: database_path,
+        persons,
+        detections,
+        images,
+        thumbnails,
+    };
 
-            // Print the names of persons in the database
-            for person in &database {
-                println!("  - {}", person.name);
+    // Quiet only silences the informational text; JSON is the requested data
+    if !(cli.quiet && cli.format == OutputFormat::Text) {
+        match report.render(cli.format) {
+            Ok(rendered) => print!("{}", rendered),
+            Err(e) => {
+                eprintln!("Error rendering output: {}", e);
+                std::process::exit(1);
             }
+        }
+    }
+
+    if let (true, Some((width, height))) = (ascii, image_dims) {
+        print_ascii_overlay(width, height, report.detections.as_deref().unwrap_or(&[]));
+    }
+
+    if failed {
+        std::process::exit(EXIT_IMAGE_ERROR);
+    }
+    let found_none = match (&report.detections, &report.images) {
+        (Some(detections), _) => detections.is_empty(),
+        (_, Some(images)) => images.iter().all(|image| image.detections.is_empty()),
+        _ => false,
+    };
+    if found_none {
+        std::process::exit(EXIT_NO_FACES);
+    }
+}
 
-            // Here you would call your processing logic
-            // For example:
-            // let image = processors::image_loader::load_image(&cli.input);
-            // let detections = processors::face_detector::detect_faces(&image);
-            // ... further processing ...
+/// Writes a thumbnail of every reference photo, reporting the photos that failed.
+///
+/// Returns the paths of the written thumbnails.
+fn generate_thumbnails(database_path: &str, persons: &[database::Person]) -> Vec<String> {
+    match database::generate_thumbnails(database_path, persons, database::DEFAULT_THUMBNAIL_SIZE) {
+        Ok(results) => {
+            for (path, error) in &results.errors {
+                eprintln!("Error generating thumbnail for {}: {}", path, error);
+            }
+            results.thumbnails
         }
         Err(e) => {
-            eprintln!("Error loading database: {}", e);
-            std::process::exit(1);
+            eprintln!("Error creating thumbnail directory: {}", e);
+            std::process::exit(EXIT_DATABASE_ERROR);
         }
     }
 }
+
+/// Detects faces in the input image, saving an annotated copy if requested.
+///
+/// Returns the detections and the dimensions of the decoded image.
+fn detect(input: &str, output: Option<&str>) -> (Vec<Detection>, (u32, u32)) {
+    let options = ProcessOptions {
+        output_path: output.map(str::to_string),
+        ..Default::default()
+    };
+
+    match process_image_with_stats(input, &options) {
+        Ok((detections, stats)) => (detections, stats.image_dims),
+        Err(e) => {
+            eprintln!("Error processing image: {}", e);
+            std::process::exit(EXIT_IMAGE_ERROR);
+        }
+    }
+}
+
+/// Detects faces in every image of a directory, showing a progress bar on a terminal.
+///
+/// Returns the per-image results and whether any image failed.
+fn detect_directory(dir: &str, allow_progress: bool) -> (Vec<ImageReport>, bool) {
+    let mut progress = progress_sink(allow_progress && std::io::stderr().is_terminal());
+
+    let results =
+        match process_directory_with_progress(dir, &DirectoryOptions::default(), &mut *progress) {
+            Ok(results) => results,
+            Err(e) => {
+                eprintln!("Error reading input directory: {}", e);
+                std::process::exit(EXIT_IMAGE_ERROR);
+            }
+        };
+
+    for (path, error) in &results.errors {
+        eprintln!("Error processing {}: {}", path, error);
+    }
+
+    let images = results
+        .detections
+        .into_iter()
+        .map(|(path, detections)| ImageReport { path, detections })
+        .collect();
+    (images, !results.errors.is_empty())
+}
+
+/// Picks the progress bar if it is wanted and compiled in.
+fn progress_sink(show: bool) -> Box<dyn Progress> {
+    #[cfg(feature = "progress")]
+    if show {
+        return Box::new(facial_recognition::utils::progress::ProgressBar::new());
+    }
+    #[cfg(not(feature = "progress"))]
+    let _ = show;
+
+    Box::new(NoProgress)
+}
+
+/// Prints the detections as an ASCII overlay sized to the decoded image.
+fn print_ascii_overlay(width: u32, height: u32, detections: &[Detection]) {
+    // Terminal cells are roughly twice as tall as they are wide
+    let rows = ((height as u64 * ASCII_COLS as u64) / (width.max(1) as u64 * 2))
+        .clamp(1, ASCII_MAX_ROWS as u64) as u32;
+    print!(
+        "{}",
+        ascii_overlay(width, height, detections, ASCII_COLS, rows)
+    );
+}
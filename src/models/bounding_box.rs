@@ -0,0 +1,299 @@
+use serde::{Deserialize, Serialize};
+use std::cmp;
+use std::error::Error;
+
+/// An axis-aligned rectangle in image pixel coordinates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BoundingBox {
+    /// Column of the left edge.
+    pub x: u32,
+
+    /// Row of the top edge.
+    pub y: u32,
+
+    /// Width of the box in pixels.
+    pub width: u32,
+
+    /// Height of the box in pixels.
+    pub height: u32,
+}
+
+impl BoundingBox {
+    /// Creates a bounding box from its top-left corner and size.
+    pub fn new(x: u32, y: u32, width: u32, height: u32) -> Self {
+        BoundingBox {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the area of the box in pixels, saturating at `u32::MAX`.
+    pub fn area(&self) -> u32 {
+        self.width.saturating_mul(self.height)
+    }
+
+    /// Returns the center point of the box as (x, y).
+    pub fn center(&self) -> (f32, f32) {
+        (
+            self.x as f32 + self.width as f32 / 2.0,
+            self.y as f32 + self.height as f32 / 2.0,
+        )
+    }
+
+    /// Computes the intersection over union with another box.
+    ///
+    /// Returns 0.0 for disjoint boxes and when both boxes have zero area.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The box to compare against.
+    ///
+    /// # Returns
+    ///
+    /// * `f32` - The overlap ratio in `0.0..=1.0`.
+    pub fn iou(&self, other: &BoundingBox) -> f32 {
+        // Edges can exceed u32 for deserialized boxes, so compute in u64
+        let left = cmp::max(self.x, other.x) as u64;
+        let top = cmp::max(self.y, other.y) as u64;
+        let right = cmp::min(
+            self.x as u64 + self.width as u64,
+            other.x as u64 + other.width as u64,
+        );
+        let bottom = cmp::min(
+            self.y as u64 + self.height as u64,
+            other.y as u64 + other.height as u64,
+        );
+
+        let intersection = if right > left && bottom > top {
+            (right - left) * (bottom - top)
+        } else {
+            0
+        };
+        // Two areas of up to (2^32)^2 each only fit together in u128
+        let union = self.width as u128 * self.height as u128
+            + other.width as u128 * other.height as u128
+            - intersection as u128;
+
+        if union == 0 {
+            0.0
+        } else {
+            intersection as f32 / union as f32
+        }
+    }
+
+    /// Checks whether a pixel lies inside the box.
+    ///
+    /// The left and top edges are inside the box, the right and bottom edges
+    /// (at `x + width` and `y + height`) are not.
+    pub fn contains_point(&self, x: u32, y: u32) -> bool {
+        x >= self.x && y >= self.y && x - self.x < self.width && y - self.y < self.height
+    }
+
+    /// Converts the box to coordinates relative to the image size.
+    ///
+    /// # Arguments
+    ///
+    /// * `image_width` - The width of the image the box belongs to.
+    /// * `image_height` - The height of the image the box belongs to.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(f32, f32, f32, f32), Box<dyn Error>>` - The `(x, y, width, height)`
+    ///   of the box as fractions of the image size, or an error if the image has
+    ///   a zero dimension.
+    pub fn to_relative(
+        &self,
+        image_width: u32,
+        image_height: u32,
+    ) -> Result<(f32, f32, f32, f32), Box<dyn Error>> {
+        check_image_dimensions(image_width, image_height)?;
+        let (w, h) = (image_width as f32, image_height as f32);
+        Ok((
+            self.x as f32 / w,
+            self.y as f32 / h,
+            self.width as f32 / w,
+            self.height as f32 / h,
+        ))
+    }
+
+    /// Creates a box from coordinates relative to the image size.
+    ///
+    /// Every value must be a finite number in `0.0..=1.0`. Pixel values are
+    /// rounded to the nearest integer, and the right and bottom edges are
+    /// clamped to the image so rounding cannot push the box past it.
+    ///
+    /// # Arguments
+    ///
+    /// * `relative` - The `(x, y, width, height)` of the box as fractions of the image size.
+    /// * `image_width` - The width of the image the box belongs to.
+    /// * `image_height` - The height of the image the box belongs to.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<BoundingBox, Box<dyn Error>>` - The box in pixel coordinates, or an
+    ///   error if the image has a zero dimension or a value is out of range.
+    pub fn from_relative(
+        (x, y, width, height): (f32, f32, f32, f32),
+        image_width: u32,
+        image_height: u32,
+    ) -> Result<BoundingBox, Box<dyn Error>> {
+        check_image_dimensions(image_width, image_height)?;
+        for value in [x, y, width, height] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(format!(
+                    "Relative coordinates must be within 0.0..=1.0, got {}",
+                    value
+                )
+                .into());
+            }
+        }
+
+        let (w, h) = (image_width as f32, image_height as f32);
+        let (x, y) = ((x * w).round() as u32, (y * h).round() as u32);
+        Ok(BoundingBox::new(
+            x,
+            y,
+            ((width * w).round() as u32).min(image_width - x),
+            ((height * h).round() as u32).min(image_height - y),
+        ))
+    }
+}
+
+fn check_image_dimensions(image_width: u32, image_height: u32) -> Result<(), Box<dyn Error>> {
+    if image_width == 0 || image_height == 0 {
+        return Err(format!(
+            "Image dimensions must be non-zero, got {}x{}",
+            image_width, image_height
+        )
+        .into());
+    }
+    Ok(())
+}
+
+impl From<(u32, u32, u32, u32)> for BoundingBox {
+    /// Converts an `(x, y, width, height)` tuple.
+    fn from((x, y, width, height): (u32, u32, u32, u32)) -> Self {
+        BoundingBox::new(x, y, width, height)
+    }
+}
+
+impl From<BoundingBox> for (u32, u32, u32, u32) {
+    /// Converts to an `(x, y, width, height)` tuple.
+    fn from(bbox: BoundingBox) -> Self {
+        (bbox.x, bbox.y, bbox.width, bbox.height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_area_and_center() {
+        let bbox = BoundingBox::new(10, 20, 30, 40);
+        assert_eq!(bbox.area(), 1200);
+        assert_eq!(bbox.center(), (25.0, 40.0));
+    }
+
+    #[test]
+    fn test_iou_overlapping() {
+        let a = BoundingBox::new(0, 0, 10, 10);
+        let b = BoundingBox::new(5, 0, 10, 10);
+        assert!((a.iou(&b) - 1.0 / 3.0).abs() < 1e-6);
+        assert_eq!(a.iou(&b), b.iou(&a));
+        assert_eq!(a.iou(&a), 1.0);
+    }
+
+    #[test]
+    fn test_iou_disjoint() {
+        let a = BoundingBox::new(0, 0, 10, 10);
+        assert_eq!(a.iou(&BoundingBox::new(20, 20, 10, 10)), 0.0);
+
+        // Boxes sharing only an edge do not overlap
+        assert_eq!(a.iou(&BoundingBox::new(10, 0, 10, 10)), 0.0);
+
+        let empty = BoundingBox::new(5, 5, 0, 0);
+        assert_eq!(empty.iou(&empty), 0.0);
+    }
+
+    #[test]
+    fn test_near_u32_max_does_not_overflow() {
+        let big = BoundingBox::new(u32::MAX - 10, u32::MAX - 10, u32::MAX, u32::MAX);
+        assert_eq!(big.area(), u32::MAX);
+        assert_eq!(big.iou(&big), 1.0);
+
+        let small = BoundingBox::new(u32::MAX - 10, u32::MAX - 10, 5, 5);
+        assert!(big.iou(&small) > 0.0);
+        assert_eq!(big.iou(&BoundingBox::new(0, 0, 10, 10)), 0.0);
+        assert!(big.contains_point(u32::MAX, u32::MAX));
+    }
+
+    #[test]
+    fn test_contains_point() {
+        let bbox = BoundingBox::new(10, 10, 5, 5);
+        assert!(bbox.contains_point(10, 10));
+        assert!(bbox.contains_point(14, 14));
+        assert!(!bbox.contains_point(15, 10));
+        assert!(!bbox.contains_point(9, 12));
+    }
+
+    #[test]
+    fn test_relative_round_trip() {
+        let bbox = BoundingBox::new(13, 7, 101, 59);
+        let relative = bbox.to_relative(640, 480).unwrap();
+        assert_eq!(
+            BoundingBox::from_relative(relative, 640, 480).unwrap(),
+            bbox
+        );
+    }
+
+    #[test]
+    fn test_relative_at_image_edge() {
+        let (x, y, width, height) = BoundingBox::new(60, 30, 40, 20)
+            .to_relative(100, 50)
+            .unwrap();
+        assert_eq!(x + width, 1.0);
+        assert_eq!(y + height, 1.0);
+        assert_eq!(
+            BoundingBox::new(0, 0, 100, 50)
+                .to_relative(100, 50)
+                .unwrap(),
+            (0.0, 0.0, 1.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_relative_zero_dimensions() {
+        let bbox = BoundingBox::new(0, 0, 1, 1);
+        assert!(bbox.to_relative(0, 10).is_err());
+        assert!(bbox.to_relative(10, 0).is_err());
+        assert!(BoundingBox::from_relative((0.0, 0.0, 0.5, 0.5), 0, 0).is_err());
+    }
+
+    #[test]
+    fn test_from_relative_validates_values() {
+        for relative in [
+            (-0.1, 0.0, 0.5, 0.5),
+            (0.0, 0.0, 1.5, 0.5),
+            (f32::NAN, 0.0, 0.5, 0.5),
+            (0.0, 0.0, 0.5, f32::INFINITY),
+        ] {
+            assert!(BoundingBox::from_relative(relative, 100, 100).is_err());
+        }
+
+        // A box reaching past the right edge is cut off at the image border
+        assert_eq!(
+            BoundingBox::from_relative((0.75, 0.0, 0.5, 1.0), 100, 100).unwrap(),
+            BoundingBox::new(75, 0, 25, 100)
+        );
+    }
+
+    #[test]
+    fn test_tuple_conversions() {
+        let bbox: BoundingBox = (1, 2, 3, 4).into();
+        assert_eq!(bbox, BoundingBox::new(1, 2, 3, 4));
+        assert_eq!(<(u32, u32, u32, u32)>::from(bbox), (1, 2, 3, 4));
+    }
+}
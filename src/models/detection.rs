@@ -1,10 +1,219 @@
+use crate::models::bounding_box::BoundingBox;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fmt::Write;
+
+/// COCO category id used for faces in exported annotations.
+pub const COCO_FACE_CATEGORY_ID: u64 = 1;
+
 /// Represents the result of a face detection.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Detection {
     /// Confidence score of the detection.
     pub confidence: f32,
 
     /// The bounding box of the detected face.
-    pub bounding_box: (u32, u32, u32, u32), // (x, y, width, height)
+    pub bounding_box: BoundingBox,
+}
+
+impl Detection {
+    /// Converts the bounding box to coordinates relative to the image size.
+    ///
+    /// See [`BoundingBox::to_relative`].
+    ///
+    /// # Arguments
+    ///
+    /// * `image_width` - The width of the image the detection belongs to.
+    /// * `image_height` - The height of the image the detection belongs to.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(f32, f32, f32, f32), Box<dyn std::error::Error>>` - The relative
+    ///   `(x, y, width, height)` of the box, or an error if the image has a zero dimension.
+    pub fn to_relative(
+        &self,
+        image_width: u32,
+        image_height: u32,
+    ) -> Result<(f32, f32, f32, f32), Box<dyn std::error::Error>> {
+        self.bounding_box.to_relative(image_width, image_height)
+    }
+
+    /// Creates a detection from a confidence and a box relative to the image size.
+    ///
+    /// See [`BoundingBox::from_relative`].
+    ///
+    /// # Arguments
+    ///
+    /// * `confidence` - The confidence of the detection.
+    /// * `relative` - The `(x, y, width, height)` of the box as fractions of the image size.
+    /// * `image_width` - The width of the image the detection belongs to.
+    /// * `image_height` - The height of the image the detection belongs to.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Detection, Box<dyn std::error::Error>>` - The detection in pixel
+    ///   coordinates, or an error if the image has a zero dimension or a value is out of range.
+    pub fn from_relative(
+        confidence: f32,
+        relative: (f32, f32, f32, f32),
+        image_width: u32,
+        image_height: u32,
+    ) -> Result<Detection, Box<dyn std::error::Error>> {
+        Ok(Detection {
+            confidence,
+            bounding_box: BoundingBox::from_relative(relative, image_width, image_height)?,
+        })
+    }
+}
+
+/// Sorts detections by decreasing confidence.
+///
+/// The sort is stable, so detections with equal confidence keep their order.
+///
+/// # Arguments
+///
+/// * `detections` - The detections to sort in place.
+pub fn sort_by_confidence_desc(detections: &mut [Detection]) {
+    detections.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+}
+
+/// Keeps only the `k` most confident detections.
+///
+/// # Arguments
+///
+/// * `detections` - The detections to filter.
+/// * `k` - The maximum number of detections to keep.
+///
+/// # Returns
+///
+/// * `Vec<Detection>` - At most `k` detections, sorted by decreasing confidence.
+pub fn top_k(mut detections: Vec<Detection>, k: usize) -> Vec<Detection> {
+    sort_by_confidence_desc(&mut detections);
+    detections.truncate(k);
+    detections
+}
+
+/// Converts detections to COCO result annotations.
+///
+/// # Arguments
+///
+/// * `detections` - The detections to export.
+/// * `image_id` - The COCO id of the image the detections belong to.
+///
+/// # Returns
+///
+/// * `serde_json::Value` - A JSON array with one `{image_id, category_id, bbox, score}`
+///   object per detection, where `bbox` is `[x, y, width, height]`.
+pub fn to_coco(detections: &[Detection], image_id: u64) -> serde_json::Value {
+    detections
+        .iter()
+        .map(|detection| {
+            let bbox = detection.bounding_box;
+            json!({
+                "image_id": image_id,
+                "category_id": COCO_FACE_CATEGORY_ID,
+                "bbox": [bbox.x, bbox.y, bbox.width, bbox.height],
+                "score": detection.confidence,
+            })
+        })
+        .collect()
+}
+
+/// Reads detections back from COCO result annotations.
+///
+/// COCO stores boxes as floats (e.g. `[12.5, 40.0, 30.2, 30.2]`); they are
+/// rounded to the nearest pixel.
+///
+/// # Arguments
+///
+/// * `annotations` - A JSON array of COCO annotations with `bbox` and `score`.
+///
+/// # Returns
+///
+/// * `Ok(Vec<Detection>)` - The detections, in order.
+/// * `Err(Box<dyn std::error::Error>)` - An error if an annotation is malformed or a
+///   bbox value is negative, not finite or larger than `u32::MAX`.
+pub fn from_coco(
+    annotations: &serde_json::Value,
+) -> Result<Vec<Detection>, Box<dyn std::error::Error>> {
+    #[derive(Deserialize)]
+    struct Annotation {
+        bbox: [f64; 4],
+        score: f32,
+    }
+
+    let annotations: Vec<Annotation> = serde_json::from_value(annotations.clone())?;
+    annotations
+        .into_iter()
+        .map(|annotation| {
+            let mut pixels = [0; 4];
+            for (pixel, value) in pixels.iter_mut().zip(annotation.bbox) {
+                let rounded = value.round();
+                if !(0.0..=u32::MAX as f64).contains(&rounded) {
+                    return Err(format!("Invalid COCO bbox value {}", value).into());
+                }
+                *pixel = rounded as u32;
+            }
+            let [x, y, width, height] = pixels;
+            Ok(Detection {
+                confidence: annotation.score,
+                bounding_box: BoundingBox::new(x, y, width, height),
+            })
+        })
+        .collect()
+}
+
+/// Converts detections to a Pascal VOC annotation.
+///
+/// Boxes use VOC's 1-based inclusive pixel coordinates, so a box at
+/// `x = 0` with width 10 becomes `xmin = 1`, `xmax = 10`. Imported boxes may
+/// extend past the image, so boxes are clipped to it, and empty boxes or
+/// boxes entirely outside the image are left out.
+///
+/// # Arguments
+///
+/// * `detections` - The detections to export.
+/// * `image_size` - The (width, height) of the image.
+///
+/// # Returns
+///
+/// * `String` - The `<annotation>` XML document, with one `face` object per detection.
+pub fn to_pascal_voc_xml(detections: &[Detection], image_size: (u32, u32)) -> String {
+    let mut xml = String::new();
+
+    // Writing to a String cannot fail
+    let _ = writeln!(xml, "<annotation>");
+    let _ = writeln!(xml, "  <size>");
+    let _ = writeln!(xml, "    <width>{}</width>", image_size.0);
+    let _ = writeln!(xml, "    <height>{}</height>", image_size.1);
+    let _ = writeln!(xml, "    <depth>3</depth>");
+    let _ = writeln!(xml, "  </size>");
+    let (width, height) = image_size;
+    for detection in detections {
+        let bbox = detection.bounding_box;
+        if bbox.width == 0 || bbox.height == 0 || bbox.x >= width || bbox.y >= height {
+            continue;
+        }
+        let xmin = bbox.x + 1;
+        let ymin = bbox.y + 1;
+        let xmax = bbox.x.saturating_add(bbox.width).min(width);
+        let ymax = bbox.y.saturating_add(bbox.height).min(height);
+        let _ = writeln!(xml, "  <object>");
+        let _ = writeln!(xml, "    <name>face</name>");
+        let _ = writeln!(xml, "    <pose>Unspecified</pose>");
+        let _ = writeln!(xml, "    <truncated>0</truncated>");
+        let _ = writeln!(xml, "    <difficult>0</difficult>");
+        let _ = writeln!(xml, "    <bndbox>");
+        let _ = writeln!(xml, "      <xmin>{}</xmin>", xmin);
+        let _ = writeln!(xml, "      <ymin>{}</ymin>", ymin);
+        let _ = writeln!(xml, "      <xmax>{}</xmax>", xmax);
+        let _ = writeln!(xml, "      <ymax>{}</ymax>", ymax);
+        let _ = writeln!(xml, "    </bndbox>");
+        let _ = writeln!(xml, "  </object>");
+    }
+    let _ = writeln!(xml, "</annotation>");
+
+    xml
 }
 
 #[cfg(test)]
@@ -15,10 +224,162 @@ mod tests {
     fn test_detection_struct() {
         let detection = Detection {
             confidence: 0.95,
-            bounding_box: (10, 10, 100, 100),
+            bounding_box: BoundingBox::new(10, 10, 100, 100),
         };
 
         assert_eq!(detection.confidence, 0.95);
-        assert_eq!(detection.bounding_box, (10, 10, 100, 100));
+        assert_eq!(detection.bounding_box, BoundingBox::new(10, 10, 100, 100));
+    }
+
+    #[test]
+    fn test_detection_json_round_trip() {
+        let detection = Detection {
+            confidence: 0.75,
+            bounding_box: BoundingBox::new(10, 20, 30, 40),
+        };
+
+        let json = serde_json::to_string(&detection).unwrap();
+        assert_eq!(
+            json,
+            r#"{"confidence":0.75,"bounding_box":{"x":10,"y":20,"width":30,"height":40}}"#
+        );
+
+        let parsed: Detection = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, detection);
+    }
+
+    fn sample_detections() -> Vec<Detection> {
+        vec![
+            Detection {
+                confidence: 0.5,
+                bounding_box: BoundingBox::new(0, 0, 10, 20),
+            },
+            Detection {
+                confidence: 0.875,
+                bounding_box: BoundingBox::new(30, 40, 50, 60),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_relative_round_trip() {
+        for detection in sample_detections() {
+            let relative = detection.to_relative(640, 480).unwrap();
+            let restored =
+                Detection::from_relative(detection.confidence, relative, 640, 480).unwrap();
+            assert_eq!(restored, detection);
+        }
+
+        assert!(sample_detections()[0].to_relative(0, 480).is_err());
+        assert!(Detection::from_relative(0.5, (0.0, 0.0, 2.0, 0.5), 640, 480).is_err());
+    }
+
+    #[test]
+    fn test_sort_by_confidence_desc_is_stable() {
+        let make = |confidence, x| Detection {
+            confidence,
+            bounding_box: BoundingBox::new(x, 0, 10, 10),
+        };
+        let mut detections = vec![make(0.4, 0), make(0.9, 1), make(0.4, 2), make(0.7, 3)];
+
+        sort_by_confidence_desc(&mut detections);
+        let order: Vec<u32> = detections.iter().map(|d| d.bounding_box.x).collect();
+        assert_eq!(order, [1, 3, 0, 2]);
+        assert!(detections
+            .windows(2)
+            .all(|pair| pair[0].confidence >= pair[1].confidence));
+    }
+
+    #[test]
+    fn test_top_k() {
+        let top = top_k(sample_detections(), 1);
+        assert_eq!(top.len(), 1);
+        assert_eq!(top[0].confidence, 0.875);
+
+        // k larger than the input keeps everything
+        assert_eq!(top_k(sample_detections(), 10).len(), 2);
+        assert!(top_k(sample_detections(), 0).is_empty());
+    }
+
+    #[test]
+    fn test_coco_round_trip() {
+        let coco = to_coco(&sample_detections(), 7);
+        assert_eq!(coco[1]["image_id"], 7);
+        assert_eq!(coco[1]["category_id"], COCO_FACE_CATEGORY_ID);
+        assert_eq!(coco[1]["bbox"], json!([30, 40, 50, 60]));
+        assert_eq!(coco[1]["score"], 0.875);
+
+        let parsed = from_coco(&coco).unwrap();
+        assert_eq!(parsed, sample_detections());
+    }
+
+    #[test]
+    fn test_coco_empty() {
+        let coco = to_coco(&[], 1);
+        assert_eq!(coco, json!([]));
+        assert!(from_coco(&coco).unwrap().is_empty());
+        assert!(from_coco(&json!([{ "bbox": [1, 2] }])).is_err());
+    }
+
+    #[test]
+    fn test_from_coco_float_bboxes() {
+        let coco = json!([{ "image_id": 1, "bbox": [12.5, 40.0, 30.2, 29.7], "score": 0.9 }]);
+        let parsed = from_coco(&coco).unwrap();
+        assert_eq!(parsed[0].bounding_box, BoundingBox::new(13, 40, 30, 30));
+
+        for bad in [json!(-5.0), json!(1e12)] {
+            let coco = json!([{ "bbox": [bad, 0.0, 1.0, 1.0], "score": 0.5 }]);
+            assert!(from_coco(&coco).is_err());
+        }
+    }
+
+    #[test]
+    fn test_pascal_voc_xml() {
+        let xml = to_pascal_voc_xml(&sample_detections(), (640, 480));
+        assert!(xml.starts_with("<annotation>\n"));
+        assert!(xml.contains("<width>640</width>"));
+        assert!(xml.contains("<height>480</height>"));
+        assert_eq!(xml.matches("<object>").count(), 2);
+        assert!(xml.contains(
+            "<bndbox>\n      <xmin>31</xmin>\n      <ymin>41</ymin>\n      \
+             <xmax>80</xmax>\n      <ymax>100</ymax>\n    </bndbox>"
+        ));
+    }
+
+    #[test]
+    fn test_pascal_voc_xml_clamps_to_image() {
+        let detections = [Detection {
+            confidence: 0.5,
+            bounding_box: BoundingBox::new(600, 470, u32::MAX, 20),
+        }];
+        let xml = to_pascal_voc_xml(&detections, (640, 480));
+        assert!(xml.contains(
+            "<bndbox>\n      <xmin>601</xmin>\n      <ymin>471</ymin>\n      \
+             <xmax>640</xmax>\n      <ymax>480</ymax>\n    </bndbox>"
+        ));
+    }
+
+    #[test]
+    fn test_pascal_voc_xml_skips_degenerate_boxes() {
+        let detections = [
+            BoundingBox::new(10, 10, 0, 10),
+            BoundingBox::new(10, 10, 10, 0),
+            BoundingBox::new(640, 10, 10, 10),
+            BoundingBox::new(u32::MAX - 1, u32::MAX - 1, u32::MAX, u32::MAX),
+        ]
+        .map(|bounding_box| Detection {
+            confidence: 0.5,
+            bounding_box,
+        });
+        let xml = to_pascal_voc_xml(&detections, (640, 480));
+        assert!(!xml.contains("<object>"));
+    }
+
+    #[test]
+    fn test_pascal_voc_xml_empty() {
+        let xml = to_pascal_voc_xml(&[], (64, 48));
+        assert!(xml.contains("<size>"));
+        assert!(!xml.contains("<object>"));
+        assert!(xml.trim_end().ends_with("</annotation>"));
     }
 }
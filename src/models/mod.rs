@@ -1,2 +1,3 @@
+pub mod bounding_box;
 pub mod detection;
 pub mod face;
@@ -0,0 +1,126 @@
+use image::{DynamicImage, Rgb};
+use imageproc::geometric_transformations::{warp, Interpolation, Projection};
+
+/// Where `align_face` places the left eye, as fractions of the crop width and height.
+pub const LEFT_EYE_POSITION: (f32, f32) = (0.35, 0.4);
+
+/// Where `align_face` places the right eye, as fractions of the crop width and height.
+pub const RIGHT_EYE_POSITION: (f32, f32) = (0.65, 0.4);
+
+/// Rotates and scales a face crop so that the eyes sit at canonical positions.
+///
+/// After alignment the eyes lie on a horizontal line at `LEFT_EYE_POSITION`
+/// and `RIGHT_EYE_POSITION`, which removes in-plane rotation and normalizes
+/// the face size. The output has the same dimensions as the input, and areas
+/// mapped from outside the crop are black. A crop whose eye positions
+/// coincide is returned unchanged.
+///
+/// # Arguments
+///
+/// * `face` - A reference to the face crop.
+/// * `left_eye` - The (x, y) position of the left eye in the crop, in pixels.
+/// * `right_eye` - The (x, y) position of the right eye in the crop, in pixels.
+///
+/// # Returns
+///
+/// * `DynamicImage` - The aligned face crop.
+pub fn align_face(
+    face: &DynamicImage,
+    left_eye: (f32, f32),
+    right_eye: (f32, f32),
+) -> DynamicImage {
+    let (dx, dy) = (right_eye.0 - left_eye.0, right_eye.1 - left_eye.1);
+    let eye_distance = dx.hypot(dy);
+    if eye_distance == 0.0 {
+        return face.clone();
+    }
+
+    let (width, height) = (face.width() as f32, face.height() as f32);
+    let target_distance = (RIGHT_EYE_POSITION.0 - LEFT_EYE_POSITION.0) * width;
+    let scale = target_distance / eye_distance;
+    let eyes_center = (
+        (left_eye.0 + right_eye.0) / 2.0,
+        (left_eye.1 + right_eye.1) / 2.0,
+    );
+    let target_center = (
+        (LEFT_EYE_POSITION.0 + RIGHT_EYE_POSITION.0) / 2.0 * width,
+        (LEFT_EYE_POSITION.1 + RIGHT_EYE_POSITION.1) / 2.0 * height,
+    );
+
+    let projection = Projection::translate(-eyes_center.0, -eyes_center.1)
+        .and_then(Projection::rotate(-dy.atan2(dx)))
+        .and_then(Projection::scale(scale, scale))
+        .and_then(Projection::translate(target_center.0, target_center.1));
+
+    DynamicImage::ImageRgb8(warp(
+        &face.to_rgb8(),
+        &projection,
+        Interpolation::Bilinear,
+        Rgb([0, 0, 0]),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::RgbImage;
+
+    const SIZE: u32 = 120;
+
+    /// Draws two 5x5 white eyes on a black crop.
+    fn face_with_eyes(left: (f32, f32), right: (f32, f32)) -> DynamicImage {
+        let mut image = RgbImage::new(SIZE, SIZE);
+        for (cx, cy) in [left, right] {
+            for y in cy as u32 - 2..=cy as u32 + 2 {
+                for x in cx as u32 - 2..=cx as u32 + 2 {
+                    image.put_pixel(x, y, Rgb([255, 255, 255]));
+                }
+            }
+        }
+        DynamicImage::ImageRgb8(image)
+    }
+
+    /// Returns the centroid of the bright pixels in the left and right half.
+    fn eye_centroids(image: &DynamicImage) -> ((f32, f32), (f32, f32)) {
+        let mut sums = [(0.0, 0.0, 0.0); 2];
+        for (x, y, pixel) in image.to_rgb8().enumerate_pixels() {
+            if pixel[0] > 128 {
+                let half = &mut sums[(x >= SIZE / 2) as usize];
+                *half = (half.0 + x as f32, half.1 + y as f32, half.2 + 1.0);
+            }
+        }
+        let [left, right] = sums.map(|(sx, sy, n)| (sx / n, sy / n));
+        (left, right)
+    }
+
+    #[test]
+    fn test_align_face_levels_rotated_eyes() {
+        // An eye pair 40 pixels apart, rotated by 20 degrees about the center
+        let theta = 20f32.to_radians();
+        let (cx, cy) = (60.0, 60.0);
+        let (ox, oy) = (20.0 * theta.cos(), 20.0 * theta.sin());
+        let left_eye = (cx - ox, cy - oy);
+        let right_eye = (cx + ox, cy + oy);
+
+        let aligned = align_face(&face_with_eyes(left_eye, right_eye), left_eye, right_eye);
+        assert_eq!(aligned.width(), SIZE);
+        assert_eq!(aligned.height(), SIZE);
+
+        let (left, right) = eye_centroids(&aligned);
+        let angle = (right.1 - left.1).atan2(right.0 - left.0).to_degrees();
+        assert!(angle.abs() < 2.0, "eyes still tilted by {angle} degrees");
+
+        let expected_left = (
+            LEFT_EYE_POSITION.0 * SIZE as f32,
+            LEFT_EYE_POSITION.1 * SIZE as f32,
+        );
+        assert!((left.0 - expected_left.0).abs() < 2.0);
+        assert!((left.1 - expected_left.1).abs() < 2.0);
+    }
+
+    #[test]
+    fn test_align_face_coincident_eyes() {
+        let face = face_with_eyes((30.0, 30.0), (90.0, 30.0));
+        assert_eq!(align_face(&face, (50.0, 50.0), (50.0, 50.0)), face);
+    }
+}
@@ -0,0 +1,177 @@
+use crate::models::bounding_box::BoundingBox;
+use crate::models::detection::Detection;
+use image::{DynamicImage, Rgb, RgbImage};
+use imageproc::drawing::draw_hollow_rect_mut;
+use imageproc::rect::Rect;
+
+/// Thickness of the drawn bounding boxes in pixels.
+const BOX_THICKNESS: u32 = 2;
+
+/// Size in pixels of one dot of the confidence label font.
+const LABEL_SCALE: u32 = 2;
+
+/// 3x5 bitmap glyphs for the characters used in confidence labels.
+/// Each row is stored in the low three bits, most significant bit leftmost.
+const GLYPHS: [(char, [u8; 5]); 11] = [
+    ('0', [0b111, 0b101, 0b101, 0b101, 0b111]),
+    ('1', [0b010, 0b110, 0b010, 0b010, 0b111]),
+    ('2', [0b111, 0b001, 0b111, 0b100, 0b111]),
+    ('3', [0b111, 0b001, 0b111, 0b001, 0b111]),
+    ('4', [0b101, 0b101, 0b111, 0b001, 0b001]),
+    ('5', [0b111, 0b100, 0b111, 0b001, 0b111]),
+    ('6', [0b111, 0b100, 0b111, 0b101, 0b111]),
+    ('7', [0b111, 0b001, 0b001, 0b001, 0b001]),
+    ('8', [0b111, 0b101, 0b111, 0b101, 0b111]),
+    ('9', [0b111, 0b101, 0b111, 0b001, 0b111]),
+    ('.', [0b000, 0b000, 0b000, 0b000, 0b010]),
+];
+
+/// Draws a box and a confidence label over each detection.
+///
+/// Box colors scale with confidence, from red for weak detections to green
+/// for strong ones. Boxes are clipped to the image; boxes entirely outside it
+/// are not drawn.
+///
+/// # Arguments
+///
+/// * `image` - A reference to the original `image::DynamicImage`.
+/// * `detections` - The detections to draw.
+///
+/// # Returns
+///
+/// * `DynamicImage` - An RGB copy of the image with the annotations drawn on it.
+pub fn draw_detections(image: &DynamicImage, detections: &[Detection]) -> DynamicImage {
+    let mut canvas = image.to_rgb8();
+
+    for detection in detections {
+        let BoundingBox {
+            x,
+            y,
+            width,
+            height,
+        } = detection.bounding_box;
+        // Deserialized boxes may lie partly or fully outside the canvas
+        if x >= canvas.width() || y >= canvas.height() {
+            continue;
+        }
+        let width = width.min(canvas.width() - x);
+        let height = height.min(canvas.height() - y);
+        let color = confidence_color(detection.confidence);
+
+        for inset in 0..BOX_THICKNESS {
+            if width <= 2 * inset || height <= 2 * inset {
+                break;
+            }
+            let rect = Rect::at((x + inset) as i32, (y + inset) as i32)
+                .of_size(width - 2 * inset, height - 2 * inset);
+            draw_hollow_rect_mut(&mut canvas, rect, color);
+        }
+
+        // Put the label above the box, or just inside it at the top edge
+        let label = format!("{:.2}", detection.confidence);
+        let label_height = 5 * LABEL_SCALE;
+        let label_y = if y > label_height {
+            y - label_height - 1
+        } else {
+            y.saturating_add(BOX_THICKNESS + 1)
+        };
+        draw_label(&mut canvas, &label, x, label_y, color);
+    }
+
+    DynamicImage::ImageRgb8(canvas)
+}
+
+/// Maps a confidence in `0.0..=1.0` to a color from red to green.
+fn confidence_color(confidence: f32) -> Rgb<u8> {
+    let c = confidence.clamp(0.0, 1.0);
+    Rgb([((1.0 - c) * 255.0) as u8, (c * 255.0) as u8, 0])
+}
+
+/// Draws a label with the built-in bitmap font, clipping at the image edges.
+fn draw_label(canvas: &mut RgbImage, text: &str, x: u32, y: u32, color: Rgb<u8>) {
+    let mut cursor = x;
+
+    for ch in text.chars() {
+        if let Some((_, rows)) = GLYPHS.iter().find(|(glyph, _)| *glyph == ch) {
+            for (row, bits) in rows.iter().enumerate() {
+                for col in 0..3 {
+                    if bits & (0b100 >> col) == 0 {
+                        continue;
+                    }
+                    for dy in 0..LABEL_SCALE {
+                        for dx in 0..LABEL_SCALE {
+                            let px = cursor.saturating_add(col * LABEL_SCALE + dx);
+                            let py = y.saturating_add(row as u32 * LABEL_SCALE + dy);
+                            if px < canvas.width() && py < canvas.height() {
+                                canvas.put_pixel(px, py, color);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        cursor = cursor.saturating_add(4 * LABEL_SCALE);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_draw_detections_changes_box_edges() {
+        let original = DynamicImage::ImageRgb8(RgbImage::from_pixel(100, 100, Rgb([0, 0, 255])));
+        let detection = Detection {
+            confidence: 0.8,
+            bounding_box: BoundingBox::new(20, 30, 40, 40),
+        };
+
+        let annotated = draw_detections(&original, &[detection]).to_rgb8();
+        let original = original.to_rgb8();
+
+        // Every pixel along the top and left edges is redrawn
+        for x in 20..60 {
+            assert_ne!(annotated.get_pixel(x, 30), original.get_pixel(x, 30));
+        }
+        for y in 30..70 {
+            assert_ne!(annotated.get_pixel(20, y), original.get_pixel(20, y));
+        }
+
+        // The inside of the box is untouched
+        assert_eq!(annotated.get_pixel(40, 50), original.get_pixel(40, 50));
+    }
+
+    #[test]
+    fn test_draw_detections_clips_large_boxes() {
+        let original = DynamicImage::ImageRgb8(RgbImage::from_pixel(50, 40, Rgb([0, 0, 255])));
+        let detections = [
+            Detection {
+                confidence: 0.5,
+                bounding_box: BoundingBox::new(10, 10, u32::MAX, u32::MAX),
+            },
+            Detection {
+                confidence: 0.5,
+                bounding_box: BoundingBox::new(u32::MAX - 1, u32::MAX - 1, u32::MAX, u32::MAX),
+            },
+        ];
+
+        let annotated = draw_detections(&original, &detections).to_rgb8();
+        assert_eq!(annotated.dimensions(), (50, 40));
+
+        // The first box is cut off at the right and bottom image edges
+        assert_ne!(
+            annotated.get_pixel(49, 20),
+            original.to_rgb8().get_pixel(49, 20)
+        );
+        assert_ne!(
+            annotated.get_pixel(30, 39),
+            original.to_rgb8().get_pixel(30, 39)
+        );
+    }
+
+    #[test]
+    fn test_confidence_color_scales() {
+        assert_eq!(confidence_color(0.0), Rgb([255, 0, 0]));
+        assert_eq!(confidence_color(1.0), Rgb([0, 255, 0]));
+    }
+}
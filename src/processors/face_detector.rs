@@ -1,7 +1,71 @@
-use crate::models::detection::Detection;
-use image::{DynamicImage, Pixel};
+use crate::models::bounding_box::BoundingBox;
+use crate::models::detection::{sort_by_confidence_desc, Detection};
+use crate::processors::preprocess::{preprocess, PreprocessOpts};
+use image::{DynamicImage, GenericImageView, Pixel};
+use rayon::prelude::*;
 use std::cmp;
 
+/// Color model used to decide whether a pixel is skin-colored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SkinModel {
+    /// Fixed RGB ranges; fast but sensitive to the color of the lighting.
+    #[default]
+    Rgb,
+
+    /// Cb/Cr chrominance ranges; more robust across skin tones and lighting.
+    YCbCr,
+}
+
+/// Default minimum ratio of skin pixels for a region to count as a face.
+pub const DEFAULT_SKIN_RATIO_THRESHOLD: f32 = 0.3;
+
+/// Default IoU above which overlapping detections are merged.
+pub const DEFAULT_NMS_IOU_THRESHOLD: f32 = 0.3;
+
+/// Default rotations, in degrees, tried by `detect_faces_multi_angle`.
+pub const DEFAULT_ROTATION_ANGLES: [f32; 3] = [-30.0, 0.0, 30.0];
+
+/// Mapping from a raw skin-pixel ratio onto a calibrated `0.0..=1.0` confidence.
+///
+/// Both mappings send the detection threshold to 0.0 and a region made
+/// entirely of skin pixels to 1.0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Calibration {
+    /// Linear rescale of `threshold..=1.0` onto `0.0..=1.0`.
+    Linear,
+
+    /// Logistic curve centered on `midpoint`, rescaled to the same end points.
+    /// Larger `steepness` values separate ratios around the midpoint more sharply.
+    Sigmoid { midpoint: f32, steepness: f32 },
+}
+
+/// Configuration of the skin-tone detector.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DetectorConfig {
+    /// Minimum ratio of skin pixels for a region to count as a face.
+    pub threshold: f32,
+
+    /// Color model used to classify skin pixels.
+    pub model: SkinModel,
+
+    /// Preprocessing applied before skin analysis.
+    pub preprocess: PreprocessOpts,
+
+    /// Calibration applied to the confidences, or `None` to report the raw ratio.
+    pub calibration: Option<Calibration>,
+}
+
+impl Default for DetectorConfig {
+    fn default() -> Self {
+        DetectorConfig {
+            threshold: DEFAULT_SKIN_RATIO_THRESHOLD,
+            model: SkinModel::default(),
+            preprocess: PreprocessOpts::default(),
+            calibration: None,
+        }
+    }
+}
+
 /// Detects faces in an image using a simple skin tone detection algorithm.
 ///
 /// # Arguments
@@ -12,11 +76,369 @@ use std::cmp;
 ///
 /// * `Vec<Detection>` - A vector of detected faces.
 pub fn detect_faces(image: &DynamicImage) -> Vec<Detection> {
-    // Convert the image to grayscale for simpler processing
-    let gray_image = image.to_luma8();
+    detect_faces_with_threshold(image, DEFAULT_SKIN_RATIO_THRESHOLD)
+}
+
+/// Detects faces in an image using a custom skin-pixel ratio threshold.
+///
+/// A region is reported when its ratio of skin pixels is strictly greater
+/// than `min_ratio`, so lower values increase sensitivity.
+///
+/// # Arguments
+///
+/// * `image` - A reference to a `image::DynamicImage`.
+/// * `min_ratio` - The skin-pixel ratio cutoff, clamped to `0.0..=1.0`.
+///
+/// # Returns
+///
+/// * `Vec<Detection>` - A vector of detected faces.
+pub fn detect_faces_with_threshold(image: &DynamicImage, min_ratio: f32) -> Vec<Detection> {
+    scan_for_faces(
+        image,
+        &PreprocessOpts::default(),
+        min_ratio,
+        SkinModel::default(),
+    )
+}
+
+/// Detects faces in an image using the given skin color model.
+///
+/// # Arguments
+///
+/// * `image` - A reference to a `image::DynamicImage`.
+/// * `model` - The color model used to classify skin pixels.
+///
+/// # Returns
+///
+/// * `Vec<Detection>` - A vector of detected faces.
+pub fn detect_faces_with_model(image: &DynamicImage, model: SkinModel) -> Vec<Detection> {
+    scan_for_faces(
+        image,
+        &PreprocessOpts::default(),
+        DEFAULT_SKIN_RATIO_THRESHOLD,
+        model,
+    )
+}
+
+/// Detects faces in an image, preprocessing it before skin analysis.
+///
+/// Blurring suppresses the isolated noisy pixels of webcam frames, and
+/// histogram equalization compensates for poorly exposed images.
+///
+/// # Arguments
+///
+/// * `image` - A reference to a `image::DynamicImage`.
+/// * `opts` - The preprocessing to apply before skin analysis.
+///
+/// # Returns
+///
+/// * `Vec<Detection>` - A vector of detected faces.
+pub fn detect_faces_with_preprocess(image: &DynamicImage, opts: &PreprocessOpts) -> Vec<Detection> {
+    scan_for_faces(
+        image,
+        opts,
+        DEFAULT_SKIN_RATIO_THRESHOLD,
+        SkinModel::default(),
+    )
+}
+
+/// Detects faces in an image using a full detector configuration.
+///
+/// # Arguments
+///
+/// * `image` - A reference to a `image::DynamicImage`.
+/// * `config` - The detector configuration.
+///
+/// # Returns
+///
+/// * `Vec<Detection>` - A vector of detected faces.
+pub fn detect_faces_with_config(image: &DynamicImage, config: &DetectorConfig) -> Vec<Detection> {
+    let mut detections = scan_for_faces(image, &config.preprocess, config.threshold, config.model);
+
+    if let Some(calibration) = config.calibration {
+        for detection in &mut detections {
+            detection.confidence =
+                calibrate_confidence(detection.confidence, config.threshold, calibration);
+        }
+    }
+
+    detections
+}
+
+/// Detects faces in several rotated copies of an image to catch tilted faces.
+///
+/// The image is rotated clockwise about its center by each angle, and the
+/// boxes found in each rotated copy are mapped back to the original frame
+/// (as the axis-aligned box around the rotated box) before all detections are
+/// merged with non-max suppression.
+///
+/// # Arguments
+///
+/// * `image` - A reference to a `image::DynamicImage`.
+/// * `angles` - The rotations to try, in degrees (see `DEFAULT_ROTATION_ANGLES`).
+///
+/// # Returns
+///
+/// * `Vec<Detection>` - The merged detections in the original image's coordinates.
+pub fn detect_faces_multi_angle(image: &DynamicImage, angles: &[f32]) -> Vec<Detection> {
+    let (width, height) = image.dimensions();
+    let rgb_image = image.to_rgb8();
+
+    let mut candidates = Vec::new();
+    for &angle in angles {
+        let theta = angle.to_radians();
+        if theta == 0.0 {
+            candidates.extend(detect_faces(image));
+            continue;
+        }
+
+        // Corners rotated out of view are filled with black, which is never skin
+        let rotated = imageproc::geometric_transformations::rotate_about_center(
+            &rgb_image,
+            theta,
+            imageproc::geometric_transformations::Interpolation::Bilinear,
+            image::Rgb([0, 0, 0]),
+        );
+        for mut detection in detect_faces(&DynamicImage::ImageRgb8(rotated)) {
+            detection.bounding_box = unrotate_box(detection.bounding_box, theta, width, height);
+            if detection.bounding_box.area() > 0 {
+                candidates.push(detection);
+            }
+        }
+    }
+
+    non_max_suppression(candidates, DEFAULT_NMS_IOU_THRESHOLD)
+}
+
+/// Maps a box found in an image rotated clockwise by `theta` radians about its
+/// center back to the unrotated frame, clamped to the image.
+fn unrotate_box(bbox: BoundingBox, theta: f32, width: u32, height: u32) -> BoundingBox {
+    let (cx, cy) = (width as f32 / 2.0, height as f32 / 2.0);
+    let (sin, cos) = theta.sin_cos();
+
+    let left = bbox.x as f32;
+    let top = bbox.y as f32;
+    let right = left + bbox.width as f32;
+    let bottom = top + bbox.height as f32;
+
+    // Rotate each corner counter-clockwise by theta about the center
+    let (mut min_x, mut min_y) = (f32::MAX, f32::MAX);
+    let (mut max_x, mut max_y) = (f32::MIN, f32::MIN);
+    for (x, y) in [(left, top), (right, top), (left, bottom), (right, bottom)] {
+        let (dx, dy) = (x - cx, y - cy);
+        let ox = cos * dx + sin * dy + cx;
+        let oy = -sin * dx + cos * dy + cy;
+        min_x = min_x.min(ox);
+        min_y = min_y.min(oy);
+        max_x = max_x.max(ox);
+        max_y = max_y.max(oy);
+    }
+
+    let x0 = min_x.clamp(0.0, width as f32).round() as u32;
+    let y0 = min_y.clamp(0.0, height as f32).round() as u32;
+    let x1 = max_x.clamp(0.0, width as f32).round() as u32;
+    let y1 = max_y.clamp(0.0, height as f32).round() as u32;
+    BoundingBox::new(x0, y0, x1.saturating_sub(x0), y1.saturating_sub(y0))
+}
+
+/// Maps a raw skin-pixel ratio onto a calibrated confidence.
+///
+/// # Arguments
+///
+/// * `ratio` - The raw skin-pixel ratio of a region.
+/// * `threshold` - The detection threshold, which maps to 0.0.
+/// * `calibration` - The mapping to apply.
+///
+/// # Returns
+///
+/// * `f32` - The calibrated confidence, clamped to `0.0..=1.0`.
+pub fn calibrate_confidence(ratio: f32, threshold: f32, calibration: Calibration) -> f32 {
+    let threshold = threshold.clamp(0.0, 1.0);
+    if threshold >= 1.0 {
+        return if ratio >= 1.0 { 1.0 } else { 0.0 };
+    }
+
+    let calibrated = match calibration {
+        Calibration::Linear => (ratio - threshold) / (1.0 - threshold),
+        Calibration::Sigmoid {
+            midpoint,
+            steepness,
+        } => {
+            let sigmoid = |r: f32| 1.0 / (1.0 + (-steepness * (r - midpoint)).exp());
+            let (low, high) = (sigmoid(threshold), sigmoid(1.0));
+            if high > low {
+                (sigmoid(ratio) - low) / (high - low)
+            } else {
+                (ratio - threshold) / (1.0 - threshold)
+            }
+        }
+    };
+
+    calibrated.clamp(0.0, 1.0)
+}
+
+/// Path of the OpenCV eye Haar cascade, unless overridden by `FR_EYE_CASCADE`.
+#[cfg(feature = "opencv")]
+pub const DEFAULT_EYE_CASCADE_PATH: &str = "haarcascade_eye.xml";
+
+/// Detects faces in an image and drops regions in which no eye is found.
+///
+/// Skin-colored walls and arms pass the skin test but contain no eyes, so
+/// this removes many false positives. Without the `opencv` feature no
+/// verification is possible and every detection is kept.
+///
+/// # Arguments
+///
+/// * `image` - A reference to a `image::DynamicImage`.
+///
+/// # Returns
+///
+/// * `Vec<Detection>` - The detected faces that contain at least one eye.
+pub fn detect_faces_verified(image: &DynamicImage) -> Vec<Detection> {
+    let detections = detect_faces(image);
+    let candidate_count = detections.len();
+
+    let verified = keep_regions_with_eyes(image, detections);
+    log::debug!(
+        "Kept {} of {} detections after eye verification",
+        verified.len(),
+        candidate_count
+    );
+    verified
+}
+
+/// Drops the detections whose region contains no eye, loading the cascade once.
+///
+/// If the cascade cannot be loaded, every detection is kept and a warning is logged.
+#[cfg(feature = "opencv")]
+fn keep_regions_with_eyes(image: &DynamicImage, detections: Vec<Detection>) -> Vec<Detection> {
+    let mut classifier = match load_eye_cascade() {
+        Ok(classifier) => classifier,
+        Err(e) => {
+            log::warn!("Eye verification unavailable, keeping all regions: {}", e);
+            return detections;
+        }
+    };
+
+    detections
+        .into_iter()
+        .filter(|detection| {
+            let BoundingBox {
+                x,
+                y,
+                width,
+                height,
+            } = detection.bounding_box;
+            match count_eyes(&mut classifier, &image.crop_imm(x, y, width, height)) {
+                Ok(count) => count > 0,
+                Err(e) => {
+                    log::warn!("Eye verification failed, keeping region: {}", e);
+                    true
+                }
+            }
+        })
+        .collect()
+}
+
+/// Fallback used without the `opencv` feature: every detection is kept.
+#[cfg(not(feature = "opencv"))]
+fn keep_regions_with_eyes(_image: &DynamicImage, detections: Vec<Detection>) -> Vec<Detection> {
+    detections
+}
+
+/// Checks whether a face region contains at least one eye, using an OpenCV Haar cascade.
+///
+/// The cascade is loaded on every call; `detect_faces_verified` loads it once
+/// for all regions of an image. If the cascade cannot be loaded or run, the
+/// region is kept and a warning is logged.
+///
+/// # Arguments
+///
+/// * `region` - The cropped face region.
+///
+/// # Returns
+///
+/// * `bool` - Whether an eye was found in the region.
+#[cfg(feature = "opencv")]
+pub fn has_eyes(region: &DynamicImage) -> bool {
+    match load_eye_cascade().and_then(|mut classifier| count_eyes(&mut classifier, region)) {
+        Ok(count) => count > 0,
+        Err(e) => {
+            log::warn!("Eye verification failed, keeping region: {}", e);
+            true
+        }
+    }
+}
+
+/// Fallback used without the `opencv` feature: every region passes.
+#[cfg(not(feature = "opencv"))]
+pub fn has_eyes(_region: &DynamicImage) -> bool {
+    true
+}
+
+/// Loads the eye Haar cascade from `FR_EYE_CASCADE` or `DEFAULT_EYE_CASCADE_PATH`.
+#[cfg(feature = "opencv")]
+fn load_eye_cascade() -> Result<opencv::objdetect::CascadeClassifier, Box<dyn std::error::Error>> {
+    use opencv::prelude::*;
+
+    let cascade_path =
+        std::env::var("FR_EYE_CASCADE").unwrap_or_else(|_| DEFAULT_EYE_CASCADE_PATH.to_string());
+    let classifier = opencv::objdetect::CascadeClassifier::new(&cascade_path)?;
+    if classifier.empty()? {
+        return Err(format!("Could not load eye cascade '{}'", cascade_path).into());
+    }
+    Ok(classifier)
+}
+
+/// Runs the eye cascade over a region and returns the number of eyes found.
+#[cfg(feature = "opencv")]
+fn count_eyes(
+    classifier: &mut opencv::objdetect::CascadeClassifier,
+    region: &DynamicImage,
+) -> Result<usize, Box<dyn std::error::Error>> {
+    use opencv::core::{Mat, Rect, Size, Vector};
+    use opencv::prelude::*;
+
+    let gray = region.to_luma8();
+    let mat =
+        Mat::new_rows_cols_with_data(gray.height() as i32, gray.width() as i32, gray.as_raw())?;
+    let mut eyes = Vector::<Rect>::new();
+    classifier.detect_multi_scale(&mat, &mut eyes, 1.1, 3, 0, Size::new(0, 0), Size::new(0, 0))?;
+    Ok(eyes.len())
+}
+
+/// Scans an image for faces and merges the overlapping candidate regions.
+fn scan_for_faces(
+    image: &DynamicImage,
+    opts: &PreprocessOpts,
+    min_ratio: f32,
+    model: SkinModel,
+) -> Vec<Detection> {
+    let candidates = scan_regions(image, opts, min_ratio, model);
+    let candidate_count = candidates.len();
+    let detections = non_max_suppression(candidates, DEFAULT_NMS_IOU_THRESHOLD);
+    log::debug!(
+        "Merged {} candidate regions into {} detections",
+        candidate_count,
+        detections.len()
+    );
+    detections
+}
+
+/// Scans an image for skin-colored regions after optional preprocessing.
+///
+/// The grid scan yields many overlapping candidates for a single face;
+/// callers are expected to merge them with `non_max_suppression`.
+fn scan_regions(
+    image: &DynamicImage,
+    opts: &PreprocessOpts,
+    min_ratio: f32,
+    model: SkinModel,
+) -> Vec<Detection> {
+    let min_ratio = min_ratio.clamp(0.0, 1.0);
 
     // Get image dimensions
-    let (width, height) = gray_image.dimensions();
+    let (width, height) = image.dimensions();
 
     // For a simple implementation, we'll look for areas that might be faces
     // based on skin tone detection and size heuristics
@@ -25,39 +447,69 @@ pub fn detect_faces(image: &DynamicImage) -> Vec<Detection> {
     // like Haar cascades or a neural network, but for this example we'll implement
     // a basic skin color-based detector
 
-    let mut detections = Vec::new();
-
     // Simple skin tone detection in RGB space
     // This is a very basic approach - real face detection would be much more sophisticated
-    let rgb_image = image.to_rgb8();
+    // Convert once; only run preprocessing (which copies the image) when a step is enabled
+    let rgb_image = if *opts == PreprocessOpts::default() {
+        image.to_rgb8()
+    } else {
+        preprocess(&image.to_rgb8(), opts)
+    };
 
     // Define search parameters
-    let min_face_size = cmp::max(width, height) / 20; // Minimum face size as 1/20th of image dimension
+    // Minimum face size as 1/20th of image dimension, at least one pixel for tiny images
+    let min_face_size = cmp::max(cmp::max(width, height) / 20, 1);
     let max_face_size = cmp::min(width, height) / 2; // Maximum face size as half of smallest dimension
 
-    // Search for potential face regions
-    for y in (0..height).step_by(min_face_size as usize) {
-        for x in (0..width).step_by(min_face_size as usize) {
-            // Check a region of potential face size
-            let region_width = cmp::min(max_face_size, width - x);
-            let region_height = cmp::min(max_face_size, height - y);
-
-            if region_width >= min_face_size && region_height >= min_face_size {
-                // Analyze skin pixels in this region
-                let skin_pixel_count =
-                    count_skin_pixels(&rgb_image, x, y, region_width, region_height);
-                let total_pixels = region_width * region_height;
-
-                // If a significant portion of pixels are skin-colored, consider it a potential face
-                if total_pixels > 0 && (skin_pixel_count as f32 / total_pixels as f32) > 0.3 {
-                    // Calculate confidence based on skin pixel ratio
-                    let confidence = skin_pixel_count as f32 / total_pixels as f32;
-
-                    detections.push(Detection {
-                        confidence,
-                        bounding_box: (x, y, region_width, region_height),
-                    });
-                }
+    // Search for potential face regions, one row of regions per task.
+    // Collecting keeps the row order, so the result doesn't depend on the thread count.
+    let rows: Vec<u32> = (0..height).step_by(min_face_size as usize).collect();
+    rows.into_par_iter()
+        .flat_map_iter(|y| {
+            scan_row(
+                &rgb_image,
+                y,
+                min_face_size,
+                max_face_size,
+                min_ratio,
+                model,
+            )
+        })
+        .collect()
+}
+
+/// Scans one row of candidate regions starting at `y` for skin-colored regions.
+fn scan_row(
+    rgb_image: &image::RgbImage,
+    y: u32,
+    min_face_size: u32,
+    max_face_size: u32,
+    min_ratio: f32,
+    model: SkinModel,
+) -> Vec<Detection> {
+    let (width, height) = rgb_image.dimensions();
+    let mut detections = Vec::new();
+
+    for x in (0..width).step_by(min_face_size as usize) {
+        // Check a region of potential face size
+        let region_width = cmp::min(max_face_size, width - x);
+        let region_height = cmp::min(max_face_size, height - y);
+
+        if region_width >= min_face_size && region_height >= min_face_size {
+            // Analyze skin pixels in this region
+            let skin_pixel_count =
+                count_skin_pixels_model(rgb_image, x, y, region_width, region_height, model);
+            let total_pixels = region_width * region_height;
+
+            // If a significant portion of pixels are skin-colored, consider it a potential face
+            if total_pixels > 0 && (skin_pixel_count as f32 / total_pixels as f32) > min_ratio {
+                // Calculate confidence based on skin pixel ratio
+                let confidence = skin_pixel_count as f32 / total_pixels as f32;
+
+                detections.push(Detection {
+                    confidence,
+                    bounding_box: BoundingBox::new(x, y, region_width, region_height),
+                });
             }
         }
     }
@@ -65,31 +517,112 @@ pub fn detect_faces(image: &DynamicImage) -> Vec<Detection> {
     detections
 }
 
-/// Counts skin-colored pixels in a region of an image
-fn count_skin_pixels(image: &image::RgbImage, x: u32, y: u32, width: u32, height: u32) -> u32 {
+/// Merges overlapping detections, keeping the most confident box of each cluster.
+///
+/// Detections are visited in order of decreasing confidence, and a box is
+/// discarded when its IoU with an already kept box exceeds `iou_threshold`.
+///
+/// # Arguments
+///
+/// * `detections` - The detections to filter.
+/// * `iou_threshold` - The overlap above which a less confident box is discarded.
+///
+/// # Returns
+///
+/// * `Vec<Detection>` - The kept detections, sorted by decreasing confidence.
+pub fn non_max_suppression(mut detections: Vec<Detection>, iou_threshold: f32) -> Vec<Detection> {
+    sort_by_confidence_desc(&mut detections);
+
+    let mut kept: Vec<Detection> = Vec::with_capacity(detections.len());
+    for detection in detections {
+        if kept
+            .iter()
+            .all(|k| k.bounding_box.iou(&detection.bounding_box) <= iou_threshold)
+        {
+            kept.push(detection);
+        }
+    }
+
+    kept
+}
+
+/// Crops each detected face out of an image.
+///
+/// Boxes extending past the image edges are clamped to the image, and boxes
+/// left with zero area after clamping are skipped.
+///
+/// # Arguments
+///
+/// * `image` - A reference to the image the detections were made on.
+/// * `detections` - The detections to crop.
+///
+/// # Returns
+///
+/// * `Vec<DynamicImage>` - One cropped subimage per non-empty detection, in order.
+pub fn crop_faces(image: &DynamicImage, detections: &[Detection]) -> Vec<DynamicImage> {
+    let (image_width, image_height) = (image.width(), image.height());
+
+    detections
+        .iter()
+        .filter_map(|detection| {
+            let BoundingBox {
+                x,
+                y,
+                width,
+                height,
+            } = detection.bounding_box;
+            let width = width.min(image_width.saturating_sub(x));
+            let height = height.min(image_height.saturating_sub(y));
+
+            if width == 0 || height == 0 {
+                None
+            } else {
+                Some(image.crop_imm(x, y, width, height))
+            }
+        })
+        .collect()
+}
+
+/// Counts skin-colored pixels in a region of an image using the RGB model
+///
+/// # Arguments
+///
+/// * `image` - The RGB image to analyze.
+/// * `x`, `y`, `width`, `height` - The region to analyze; pixels outside the image are ignored.
+///
+/// # Returns
+///
+/// * `u32` - The number of skin-colored pixels in the region.
+pub fn count_skin_pixels(image: &image::RgbImage, x: u32, y: u32, width: u32, height: u32) -> u32 {
+    count_skin_pixels_model(image, x, y, width, height, SkinModel::Rgb)
+}
+
+/// Counts skin-colored pixels in a region of an image using the given color model
+///
+/// # Arguments
+///
+/// * `image` - The RGB image to analyze.
+/// * `x`, `y`, `width`, `height` - The region to analyze; pixels outside the image are ignored.
+/// * `model` - The color model used to classify skin pixels.
+///
+/// # Returns
+///
+/// * `u32` - The number of skin-colored pixels in the region.
+pub fn count_skin_pixels_model(
+    image: &image::RgbImage,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    model: SkinModel,
+) -> u32 {
     let mut count = 0;
 
-    // Simple RGB range for skin tones (very basic approximation)
-    // In a real implementation, this would be much more sophisticated
     for py in y..(y + height) {
         for px in x..(x + width) {
             if px < image.width() && py < image.height() {
                 let pixel = image.get_pixel(px, py);
-                let rgb = pixel.channels();
-                let r = rgb[0] as f32;
-                let g = rgb[1] as f32;
-                let b = rgb[2] as f32;
-
-                // Basic skin color check (very simplified)
-                // Real face detection would use more advanced techniques
-                if r > 95.0
-                    && g > 40.0
-                    && b > 20.0
-                    && r > g
-                    && r > b
-                    && (r - g) > 15.0
-                    && (r - b) > 15.0
-                {
+                if is_skin(pixel.channels(), model) {
                     count += 1;
                 }
             }
@@ -99,6 +632,28 @@ fn count_skin_pixels(image: &image::RgbImage, x: u32, y: u32, width: u32, height
     count
 }
 
+/// Checks whether an RGB pixel is skin-colored under the given color model
+fn is_skin(rgb: &[u8], model: SkinModel) -> bool {
+    let r = rgb[0] as f32;
+    let g = rgb[1] as f32;
+    let b = rgb[2] as f32;
+
+    match model {
+        SkinModel::Rgb => {
+            // Simple RGB range for skin tones (very basic approximation)
+            // Real face detection would use more advanced techniques
+            r > 95.0 && g > 40.0 && b > 20.0 && r > g && r > b && (r - g) > 15.0 && (r - b) > 15.0
+        }
+        SkinModel::YCbCr => {
+            // Chrominance ranges from the JPEG (ITU-R BT.601) conversion,
+            // ignoring luma so that brightness changes matter less
+            let cb = 128.0 - 0.168_736 * r - 0.331_264 * g + 0.5 * b;
+            let cr = 128.0 + 0.5 * r - 0.418_688 * g - 0.081_312 * b;
+            (77.0..=127.0).contains(&cb) && (133.0..=173.0).contains(&cr)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -129,7 +684,7 @@ mod tests {
 
         // Fill with skin-like color in a region
         for (x, y, pixel) in img_buffer.enumerate_pixels_mut() {
-            if x >= 50 && x < 150 && y >= 50 && y < 150 {
+            if (50..150).contains(&x) && (50..150).contains(&y) {
                 // Skin-like color in a region
                 *pixel = Rgb([180, 140, 120]);
             } else {
@@ -145,6 +700,354 @@ mod tests {
         assert!(!detections.is_empty());
     }
 
+    #[test]
+    fn test_detect_faces_threshold_monotonic() {
+        // Skin-like square on a blue background
+        let mut img_buffer: ImageBuffer<Rgb<u8>, Vec<u8>> = ImageBuffer::new(200, 200);
+        for (x, y, pixel) in img_buffer.enumerate_pixels_mut() {
+            if (50..150).contains(&x) && (50..150).contains(&y) {
+                *pixel = Rgb([180, 140, 120]);
+            } else {
+                *pixel = Rgb([0, 0, 255]);
+            }
+        }
+        let img = DynamicImage::ImageRgb8(img_buffer);
+
+        let counts: Vec<usize> = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0]
+            .iter()
+            .map(|&threshold| detect_faces_with_threshold(&img, threshold).len())
+            .collect();
+
+        assert!(counts.windows(2).all(|pair| pair[0] >= pair[1]));
+        assert!(counts[0] > counts[4]);
+        assert_eq!(counts[5], 0);
+
+        // The default threshold is preserved by detect_faces
+        assert_eq!(
+            detect_faces(&img).len(),
+            detect_faces_with_threshold(&img, DEFAULT_SKIN_RATIO_THRESHOLD).len()
+        );
+
+        // Out-of-range thresholds are clamped
+        assert_eq!(detect_faces_with_threshold(&img, 5.0).len(), 0);
+        assert_eq!(
+            detect_faces_with_threshold(&img, -1.0).len(),
+            detect_faces_with_threshold(&img, 0.0).len()
+        );
+    }
+
+    #[test]
+    fn test_detect_faces_denoising_recovers_noisy_image() {
+        // Skin-like square on a blue background
+        let mut clean: ImageBuffer<Rgb<u8>, Vec<u8>> = ImageBuffer::new(200, 200);
+        for (x, y, pixel) in clean.enumerate_pixels_mut() {
+            if (50..150).contains(&x) && (50..150).contains(&y) {
+                *pixel = Rgb([180, 140, 120]);
+            } else {
+                *pixel = Rgb([0, 0, 255]);
+            }
+        }
+
+        // Add deterministic salt-and-pepper noise to 30% of the pixels
+        let mut noisy = clean.clone();
+        let mut state: u32 = 12345;
+        for pixel in noisy.pixels_mut() {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            match (state >> 16) % 100 {
+                0..=14 => *pixel = Rgb([0, 0, 0]),
+                15..=29 => *pixel = Rgb([255, 255, 255]),
+                _ => {}
+            }
+        }
+
+        let opts = PreprocessOpts {
+            blur_sigma: Some(1.0),
+            equalize: false,
+        };
+        // Compare candidate regions before non-maximum suppression merges them
+        let count = |img: ImageBuffer<Rgb<u8>, Vec<u8>>, opts: &PreprocessOpts| {
+            scan_regions(
+                &DynamicImage::ImageRgb8(img),
+                opts,
+                DEFAULT_SKIN_RATIO_THRESHOLD,
+                SkinModel::Rgb,
+            )
+            .len() as i64
+        };
+        let clean_count = count(clean, &PreprocessOpts::default());
+        let noisy_count = count(noisy.clone(), &PreprocessOpts::default());
+        let denoised_count = count(noisy, &opts);
+
+        assert!((denoised_count - clean_count).abs() < (noisy_count - clean_count).abs());
+    }
+
+    #[test]
+    fn test_detection_independent_of_input_color_type() {
+        let mut img_buffer: ImageBuffer<Rgb<u8>, Vec<u8>> = ImageBuffer::new(200, 200);
+        for (x, y, pixel) in img_buffer.enumerate_pixels_mut() {
+            *pixel = if (40..140).contains(&x) && (40..140).contains(&y) {
+                Rgb([180, 140, 120])
+            } else {
+                Rgb([0, 0, 255])
+            };
+        }
+        let rgb = DynamicImage::ImageRgb8(img_buffer);
+        let rgba = DynamicImage::ImageRgba8(rgb.to_rgba8());
+
+        let from_rgb = detect_faces(&rgb);
+        let from_rgba = detect_faces(&rgba);
+
+        assert_eq!(from_rgb, from_rgba);
+
+        // The strongest detection sits on the skin patch
+        assert_eq!(from_rgb[0].bounding_box, BoundingBox::new(40, 40, 100, 100));
+        assert_eq!(from_rgb[0].confidence, 1.0);
+    }
+
+    #[cfg(not(feature = "opencv"))]
+    #[test]
+    fn test_detect_faces_verified_falls_back_to_all_detections() {
+        let mut img_buffer: ImageBuffer<Rgb<u8>, Vec<u8>> = ImageBuffer::new(200, 200);
+        for (x, y, pixel) in img_buffer.enumerate_pixels_mut() {
+            *pixel = if (40..140).contains(&x) && (40..140).contains(&y) {
+                Rgb([180, 140, 120])
+            } else {
+                Rgb([0, 0, 255])
+            };
+        }
+        let img = DynamicImage::ImageRgb8(img_buffer);
+
+        let detections = detect_faces(&img);
+        assert!(!detections.is_empty());
+        assert_eq!(detect_faces_verified(&img), detections);
+    }
+
+    /// Needs OpenCV and the eye cascade (see `FR_EYE_CASCADE`)
+    #[cfg(feature = "opencv")]
+    #[test]
+    #[ignore]
+    fn test_detect_faces_verified_rejects_plain_skin() {
+        // A uniform skin-colored wall has no eyes
+        let img = DynamicImage::ImageRgb8(ImageBuffer::from_pixel(200, 200, Rgb([180, 140, 120])));
+        assert!(!detect_faces(&img).is_empty());
+        assert!(detect_faces_verified(&img).is_empty());
+    }
+
+    #[test]
+    fn test_calibrate_confidence_end_points() {
+        let sigmoid = Calibration::Sigmoid {
+            midpoint: 0.6,
+            steepness: 10.0,
+        };
+        for calibration in [Calibration::Linear, sigmoid] {
+            assert!(calibrate_confidence(0.3, 0.3, calibration).abs() < 1e-6);
+            assert!((calibrate_confidence(1.0, 0.3, calibration) - 1.0).abs() < 1e-6);
+
+            // Monotonic in between
+            let mid = calibrate_confidence(0.5, 0.3, calibration);
+            let high = calibrate_confidence(0.8, 0.3, calibration);
+            assert!(0.0 < mid && mid < high && high < 1.0);
+        }
+
+        assert!((calibrate_confidence(0.65, 0.3, Calibration::Linear) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_detect_faces_with_config_calibrates() {
+        let img = DynamicImage::ImageRgb8(ImageBuffer::from_pixel(200, 200, Rgb([180, 140, 120])));
+
+        let raw = detect_faces_with_config(&img, &DetectorConfig::default());
+        let calibrated = detect_faces_with_config(
+            &img,
+            &DetectorConfig {
+                calibration: Some(Calibration::Linear),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(raw, detect_faces(&img));
+        assert_eq!(calibrated.len(), raw.len());
+        assert!((calibrated[0].confidence - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_unrotate_box_inverts_rotation() {
+        // A single marked pixel, rotated with imageproc, must map back onto itself
+        let mut img_buffer: ImageBuffer<Rgb<u8>, Vec<u8>> = ImageBuffer::new(100, 80);
+        img_buffer.put_pixel(70, 20, Rgb([255, 255, 255]));
+        let theta = 30f32.to_radians();
+        let rotated = imageproc::geometric_transformations::rotate_about_center(
+            &img_buffer,
+            theta,
+            imageproc::geometric_transformations::Interpolation::Nearest,
+            Rgb([0, 0, 0]),
+        );
+        let (rx, ry) = rotated
+            .enumerate_pixels()
+            .find(|(_, _, pixel)| pixel[0] > 0)
+            .map(|(x, y, _)| (x, y))
+            .expect("Marked pixel rotated out of view");
+
+        let back = unrotate_box(BoundingBox::new(rx, ry, 1, 1), theta, 100, 80);
+        assert!(back.x.abs_diff(70) <= 1 && back.y.abs_diff(20) <= 1);
+
+        // No rotation is the identity
+        let bbox = BoundingBox::new(10, 20, 30, 40);
+        assert_eq!(unrotate_box(bbox, 0.0, 100, 80), bbox);
+    }
+
+    #[test]
+    fn test_detect_faces_multi_angle_finds_tilted_patch() {
+        // Skin-colored 160x100 rectangle tilted by 30 degrees about (150, 150)
+        let (sin, cos) = 30f32.to_radians().sin_cos();
+        let mut img_buffer: ImageBuffer<Rgb<u8>, Vec<u8>> = ImageBuffer::new(300, 300);
+        for (x, y, pixel) in img_buffer.enumerate_pixels_mut() {
+            let (dx, dy) = (x as f32 - 150.0, y as f32 - 150.0);
+            let (u, v) = (cos * dx + sin * dy, -sin * dx + cos * dy);
+            *pixel = if u.abs() < 80.0 && v.abs() < 50.0 {
+                Rgb([180, 140, 120])
+            } else {
+                Rgb([0, 0, 255])
+            };
+        }
+        let img = DynamicImage::ImageRgb8(img_buffer);
+
+        let detections = detect_faces_multi_angle(&img, &DEFAULT_ROTATION_ANGLES);
+        assert!(!detections.is_empty());
+        assert!(detections[0].bounding_box.contains_point(150, 150));
+        assert!(detections
+            .iter()
+            .all(|d| d.bounding_box.x + d.bounding_box.width <= 300
+                && d.bounding_box.y + d.bounding_box.height <= 300));
+    }
+
+    #[test]
+    fn test_parallel_scan_matches_serial() {
+        // Large image with several skin-like patches of different sizes
+        let mut img_buffer: ImageBuffer<Rgb<u8>, Vec<u8>> = ImageBuffer::new(800, 600);
+        for (x, y, pixel) in img_buffer.enumerate_pixels_mut() {
+            let in_patch = ((100..300).contains(&x) && (50..250).contains(&y))
+                || ((500..560).contains(&x) && (400..470).contains(&y))
+                || ((650..790).contains(&x) && (80..380).contains(&y));
+            *pixel = if in_patch {
+                Rgb([180, 140, 120])
+            } else {
+                Rgb([0, 0, 255])
+            };
+        }
+        let img = DynamicImage::ImageRgb8(img_buffer);
+
+        let parallel = scan_regions(
+            &img,
+            &PreprocessOpts::default(),
+            DEFAULT_SKIN_RATIO_THRESHOLD,
+            SkinModel::Rgb,
+        );
+
+        // Serial reference: the same rows, scanned one after another
+        let rgb_image = img.to_rgb8();
+        let (min_face_size, max_face_size) = (800 / 20, 600 / 2);
+        let serial: Vec<Detection> = (0..600)
+            .step_by(min_face_size as usize)
+            .flat_map(|y| {
+                scan_row(
+                    &rgb_image,
+                    y,
+                    min_face_size,
+                    max_face_size,
+                    DEFAULT_SKIN_RATIO_THRESHOLD,
+                    SkinModel::Rgb,
+                )
+            })
+            .collect();
+
+        assert!(!serial.is_empty());
+        assert_eq!(parallel, serial);
+    }
+
+    #[test]
+    fn test_detect_faces_tiny_images() {
+        // Images under 20 pixels would otherwise get a zero step size
+        let skin = DynamicImage::ImageRgb8(ImageBuffer::from_pixel(16, 16, Rgb([180, 140, 120])));
+        let detections = detect_faces(&skin);
+        assert!(!detections.is_empty());
+        assert!(detections
+            .iter()
+            .all(|d| d.bounding_box.x + d.bounding_box.width <= 16));
+
+        assert!(detect_faces(&DynamicImage::new_rgb8(1, 1)).is_empty());
+        assert!(detect_faces(&DynamicImage::new_rgb8(0, 0)).is_empty());
+    }
+
+    #[test]
+    fn test_non_max_suppression_overlapping_boxes() {
+        let detections = vec![
+            Detection {
+                confidence: 0.6,
+                bounding_box: BoundingBox::new(10, 10, 50, 50),
+            },
+            Detection {
+                confidence: 0.9,
+                bounding_box: BoundingBox::new(10, 10, 50, 50),
+            },
+        ];
+
+        let kept = non_max_suppression(detections, 0.3);
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].confidence, 0.9);
+    }
+
+    #[test]
+    fn test_non_max_suppression_disjoint_boxes() {
+        let detections = vec![
+            Detection {
+                confidence: 0.6,
+                bounding_box: BoundingBox::new(0, 0, 20, 20),
+            },
+            Detection {
+                confidence: 0.9,
+                bounding_box: BoundingBox::new(50, 50, 20, 20),
+            },
+        ];
+
+        let kept = non_max_suppression(detections, 0.3);
+        assert_eq!(kept.len(), 2);
+    }
+
+    #[test]
+    fn test_crop_faces_in_bounds() {
+        let img = DynamicImage::ImageRgb8(ImageBuffer::new(100, 80));
+        let detections = vec![Detection {
+            confidence: 0.9,
+            bounding_box: BoundingBox::new(10, 20, 30, 40),
+        }];
+
+        let crops = crop_faces(&img, &detections);
+        assert_eq!(crops.len(), 1);
+        assert_eq!((crops[0].width(), crops[0].height()), (30, 40));
+    }
+
+    #[test]
+    fn test_crop_faces_clamps_and_skips() {
+        let img = DynamicImage::ImageRgb8(ImageBuffer::new(100, 80));
+        let detections = vec![
+            // Partially off the right edge
+            Detection {
+                confidence: 0.9,
+                bounding_box: BoundingBox::new(80, 10, 50, 20),
+            },
+            // Entirely outside the image
+            Detection {
+                confidence: 0.8,
+                bounding_box: BoundingBox::new(120, 10, 20, 20),
+            },
+        ];
+
+        let crops = crop_faces(&img, &detections);
+        assert_eq!(crops.len(), 1);
+        assert_eq!((crops[0].width(), crops[0].height()), (20, 20));
+    }
+
     #[test]
     fn test_count_skin_pixels() {
         // Create a test image
@@ -180,4 +1083,42 @@ mod tests {
         // Should have 0 skin pixels
         assert_eq!(count, 0);
     }
+
+    #[test]
+    fn test_count_skin_pixels_ycbcr_catches_dim_skin() {
+        // Dimly lit skin that is too dark for the RGB rule (r <= 95)
+        let img_buffer: ImageBuffer<Rgb<u8>, Vec<u8>> =
+            ImageBuffer::from_pixel(10, 10, Rgb([90, 60, 45]));
+
+        let rgb_count = count_skin_pixels_model(&img_buffer, 0, 0, 10, 10, SkinModel::Rgb);
+        let ycbcr_count = count_skin_pixels_model(&img_buffer, 0, 0, 10, 10, SkinModel::YCbCr);
+        assert_eq!(rgb_count, 0);
+        assert_eq!(ycbcr_count, 100);
+    }
+
+    #[test]
+    fn test_count_skin_pixels_ycbcr_rejects_blue() {
+        let img_buffer: ImageBuffer<Rgb<u8>, Vec<u8>> =
+            ImageBuffer::from_pixel(10, 10, Rgb([0, 0, 255]));
+
+        let count = count_skin_pixels_model(&img_buffer, 0, 0, 10, 10, SkinModel::YCbCr);
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn test_detect_faces_with_model_dim_skin() {
+        // Dim skin-like square on a blue background
+        let mut img_buffer: ImageBuffer<Rgb<u8>, Vec<u8>> = ImageBuffer::new(200, 200);
+        for (x, y, pixel) in img_buffer.enumerate_pixels_mut() {
+            if (50..150).contains(&x) && (50..150).contains(&y) {
+                *pixel = Rgb([90, 60, 45]);
+            } else {
+                *pixel = Rgb([0, 0, 255]);
+            }
+        }
+        let img = DynamicImage::ImageRgb8(img_buffer);
+
+        assert!(detect_faces(&img).is_empty());
+        assert!(!detect_faces_with_model(&img, SkinModel::YCbCr).is_empty());
+    }
 }
@@ -1,5 +1,105 @@
+use std::fs::File;
+use std::io::{BufReader, Read};
+
+/// Extensions of the formats the `image` crate decodes with its default features.
+const IMAGE_CRATE_EXTENSIONS: &[&str] = &[
+    "jpg", "jpeg", "png", "gif", "bmp", "ico", "tif", "tiff", "webp", "pbm", "pgm", "ppm", "pam",
+    "tga", "dds", "hdr", "exr", "ff", "qoi",
+];
+
+/// Extensions of every format `load_image` can decode in this build.
+#[cfg(not(feature = "heif"))]
+const SUPPORTED_EXTENSIONS: &[&str] = IMAGE_CRATE_EXTENSIONS;
+
+/// Extensions of every format `load_image` can decode in this build.
+#[cfg(feature = "heif")]
+const SUPPORTED_EXTENSIONS: &[&str] = &[
+    "jpg", "jpeg", "png", "gif", "bmp", "ico", "tif", "tiff", "webp", "pbm", "pgm", "ppm", "pam",
+    "tga", "dds", "hdr", "exr", "ff", "qoi", "heic", "heif",
+];
+
+/// Returns the file extensions of the image formats `load_image` can decode.
+///
+/// HEIC/HEIF is only included when the `heif` feature is enabled.
+///
+/// # Returns
+///
+/// * `&'static [&'static str]` - Lowercase extensions, without the leading dot.
+pub fn supported_extensions() -> &'static [&'static str] {
+    SUPPORTED_EXTENSIONS
+}
+
+/// Extensions of supported formats that have no magic bytes to sniff.
+const SIGNATURELESS_EXTENSIONS: &[&str] = &["tga"];
+
+/// Checks whether a file holds an image `load_image` can decode.
+///
+/// The file's content decides: a PDF or text file named `.jpg` is rejected
+/// and an extensionless JPEG is accepted. Only formats without any signature
+/// (TGA) are accepted by their file extension.
+///
+/// # Arguments
+///
+/// * `path` - A string slice that holds the path to the file.
+///
+/// # Returns
+///
+/// * `bool` - `true` if the file exists and is in a supported format.
+pub fn is_supported_image(path: &str) -> bool {
+    match infer::get_from_path(path) {
+        Ok(Some(kind)) => is_supported_extension(kind.extension()),
+        // infer misses some formats the image crate knows, e.g. PNM and QOI
+        Ok(None) => is_supported_by_image_signature(path) || has_signatureless_extension(path),
+        Err(_) => false,
+    }
+}
+
+/// Checks whether the image crate recognizes the file header as a supported format.
+fn is_supported_by_image_signature(path: &str) -> bool {
+    let mut header = Vec::with_capacity(32);
+    let read = File::open(path).and_then(|file| file.take(32).read_to_end(&mut header));
+    if read.is_err() {
+        return false;
+    }
+
+    image::guess_format(&header)
+        .map(|format| {
+            format
+                .extensions_str()
+                .iter()
+                .any(|ext| is_supported_extension(ext))
+        })
+        .unwrap_or(false)
+}
+
+/// Checks whether the file extension names a format without magic bytes.
+fn has_signatureless_extension(path: &str) -> bool {
+    std::path::Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| {
+            SIGNATURELESS_EXTENSIONS
+                .iter()
+                .any(|signatureless| ext.eq_ignore_ascii_case(signatureless))
+        })
+        .unwrap_or(false)
+}
+
+/// Checks an extension against `supported_extensions`, ignoring case.
+fn is_supported_extension(ext: &str) -> bool {
+    supported_extensions()
+        .iter()
+        .any(|supported| ext.eq_ignore_ascii_case(supported))
+}
+
+/// Default upper bound on the number of pixels an input image may have (50 megapixels).
+pub const DEFAULT_MAX_PIXELS: u64 = 50_000_000;
+
 /// Loads an image from a file path.
 ///
+/// The image is rotated according to its EXIF orientation, see
+/// [`load_image_respecting_exif`].
+///
 /// # Arguments
 ///
 /// * `path` - A string slice that holds the path to the image file.
@@ -9,17 +109,515 @@
 /// * `Ok(image::DynamicImage)` - The loaded image.
 /// * `Err(Box<dyn std::error::Error>)` - An error if the image could not be loaded.
 pub fn load_image(path: &str) -> Result<image::DynamicImage, Box<dyn std::error::Error>> {
-    let img = image::open(path)?;
+    load_image_respecting_exif(path)
+}
+
+/// Loads an image from a file path and applies its EXIF orientation.
+///
+/// Phone cameras store portrait photos sideways and record the intended
+/// rotation in the EXIF `Orientation` tag. Files without EXIF data, or with
+/// an unreadable tag, are returned as stored.
+///
+/// HEIC/HEIF files are decoded through libheif when the `heif` feature is
+/// enabled; libheif already applies their orientation.
+///
+/// # Arguments
+///
+/// * `path` - A string slice that holds the path to the image file.
+///
+/// # Returns
+///
+/// * `Ok(image::DynamicImage)` - The loaded image, upright.
+/// * `Err(Box<dyn std::error::Error>)` - An error if the image could not be loaded.
+pub fn load_image_respecting_exif(
+    path: &str,
+) -> Result<image::DynamicImage, Box<dyn std::error::Error>> {
+    if is_heif(path) {
+        return load_heif(path);
+    }
+
+    // Like `is_supported_image`, trust the content over the extension
+    let img = image::io::Reader::open(path)?
+        .with_guessed_format()?
+        .decode()?;
+    let orientation = read_exif_orientation(path).unwrap_or(1);
+    Ok(apply_orientation(img, orientation))
+}
+
+/// Loads an image from a file path, rejecting images with more than `max_pixels` pixels.
+///
+/// The dimensions are read from the file header before decoding, so an
+/// oversized image fails fast instead of exhausting memory.
+///
+/// # Arguments
+///
+/// * `path` - A string slice that holds the path to the image file.
+/// * `max_pixels` - The maximum allowed width * height.
+///
+/// # Returns
+///
+/// * `Ok(image::DynamicImage)` - The loaded image.
+/// * `Err(Box<dyn std::error::Error>)` - An error if the image is too large or could not be loaded.
+pub fn load_image_bounded(
+    path: &str,
+    max_pixels: u64,
+) -> Result<image::DynamicImage, Box<dyn std::error::Error>> {
+    // The image crate cannot read HEIF headers; those are checked after decoding
+    if !is_heif(path) {
+        let (width, height) = image::io::Reader::open(path)?
+            .with_guessed_format()?
+            .into_dimensions()?;
+        check_pixel_limit(width, height, max_pixels)?;
+    }
+
+    let img = load_image_respecting_exif(path)?;
+    check_pixel_limit(img.width(), img.height(), max_pixels)?;
     Ok(img)
 }
 
+/// Returns an error if a `width` x `height` image exceeds `max_pixels`.
+fn check_pixel_limit(
+    width: u32,
+    height: u32,
+    max_pixels: u64,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let pixels = width as u64 * height as u64;
+    if pixels > max_pixels {
+        return Err(format!(
+            "Image is {}x{} ({} pixels), which exceeds the limit of {} pixels",
+            width, height, pixels, max_pixels
+        )
+        .into());
+    }
+    Ok(())
+}
+
+/// Checks whether a file is HEIC/HEIF, by its extension or its content.
+fn is_heif(path: &str) -> bool {
+    let by_extension = std::path::Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.eq_ignore_ascii_case("heic") || ext.eq_ignore_ascii_case("heif"))
+        .unwrap_or(false);
+
+    by_extension
+        || matches!(
+            infer::get_from_path(path),
+            Ok(Some(kind)) if kind.mime_type() == "image/heif"
+        )
+}
+
+/// Decodes a HEIC/HEIF file into an RGB image.
+#[cfg(feature = "heif")]
+fn load_heif(path: &str) -> Result<image::DynamicImage, Box<dyn std::error::Error>> {
+    use libheif_rs::{ColorSpace, HeifContext, LibHeif, RgbChroma};
+
+    let lib_heif = LibHeif::new();
+    let context = HeifContext::read_from_file(path)?;
+    let handle = context.primary_image_handle()?;
+    let decoded = lib_heif.decode(&handle, ColorSpace::Rgb(RgbChroma::Rgb), None)?;
+
+    let plane = decoded
+        .planes()
+        .interleaved
+        .ok_or("HEIF image has no interleaved RGB plane")?;
+
+    // Rows may be padded, so copy them one at a time
+    let row_len = plane.width as usize * 3;
+    let mut pixels = Vec::with_capacity(row_len * plane.height as usize);
+    for row in plane.data.chunks(plane.stride).take(plane.height as usize) {
+        pixels.extend_from_slice(&row[..row_len]);
+    }
+
+    let img = image::RgbImage::from_raw(plane.width, plane.height, pixels)
+        .ok_or("HEIF image data does not match its dimensions")?;
+    Ok(image::DynamicImage::ImageRgb8(img))
+}
+
+/// Fails with a clear error when HEIC/HEIF decoding is not compiled in.
+#[cfg(not(feature = "heif"))]
+fn load_heif(path: &str) -> Result<image::DynamicImage, Box<dyn std::error::Error>> {
+    Err(format!(
+        "HEIC support not enabled: rebuild with `--features heif` to load {}",
+        path
+    )
+    .into())
+}
+
+/// Reads the EXIF `Orientation` tag of an image file, if present.
+fn read_exif_orientation(path: &str) -> Option<u32> {
+    let file = File::open(path).ok()?;
+    read_exif_orientation_from(&mut BufReader::new(file))
+}
+
+/// Reads the EXIF `Orientation` tag from an encoded image, if present.
+fn read_exif_orientation_from<R: std::io::BufRead + std::io::Seek>(reader: &mut R) -> Option<u32> {
+    let exif = exif::Reader::new().read_from_container(reader).ok()?;
+    exif.get_field(exif::Tag::Orientation, exif::In::PRIMARY)?
+        .value
+        .get_uint(0)
+}
+
+/// Rotates and flips an image so that an EXIF orientation of `orientation`
+/// becomes upright. Unknown values leave the image unchanged.
+fn apply_orientation(img: image::DynamicImage, orientation: u32) -> image::DynamicImage {
+    match orientation {
+        2 => img.fliph(),
+        3 => img.rotate180(),
+        4 => img.flipv(),
+        5 => img.rotate90().fliph(),
+        6 => img.rotate90(),
+        7 => img.rotate270().fliph(),
+        8 => img.rotate270(),
+        _ => img,
+    }
+}
+
+/// Loads an image from an in-memory byte buffer, guessing the format from its content.
+///
+/// Like `load_image`, the image is rotated according to its EXIF orientation,
+/// so an upload gives the same result as the file it came from.
+///
+/// # Arguments
+///
+/// * `bytes` - The encoded image data.
+///
+/// # Returns
+///
+/// * `Ok(image::DynamicImage)` - The decoded image.
+/// * `Err(Box<dyn std::error::Error>)` - An error if the data could not be decoded.
+pub fn load_image_from_bytes(
+    bytes: &[u8],
+) -> Result<image::DynamicImage, Box<dyn std::error::Error>> {
+    let img = image::load_from_memory(bytes)?;
+    Ok(apply_exif_orientation_from_bytes(img, bytes))
+}
+
+/// Loads an image from an in-memory byte buffer, rejecting images with more than `max_pixels` pixels.
+///
+/// The dimensions are read from the header before decoding, so untrusted
+/// uploads cannot exhaust memory with a small, highly compressed image.
+///
+/// # Arguments
+///
+/// * `bytes` - The encoded image data.
+/// * `max_pixels` - The maximum allowed width * height.
+///
+/// # Returns
+///
+/// * `Ok(image::DynamicImage)` - The decoded image.
+/// * `Err(Box<dyn std::error::Error>)` - An error if the image is too large or could not be decoded.
+pub fn load_image_from_bytes_bounded(
+    bytes: &[u8],
+    max_pixels: u64,
+) -> Result<image::DynamicImage, Box<dyn std::error::Error>> {
+    let (width, height) = image::io::Reader::new(std::io::Cursor::new(bytes))
+        .with_guessed_format()?
+        .into_dimensions()?;
+    check_pixel_limit(width, height, max_pixels)?;
+    load_image_from_bytes(bytes)
+}
+
+/// Loads an image from an in-memory byte buffer in an explicit format.
+///
+/// Useful for data whose format cannot be guessed from its header. The EXIF
+/// orientation is applied as in `load_image_from_bytes`.
+///
+/// # Arguments
+///
+/// * `bytes` - The encoded image data.
+/// * `format` - The format the data is encoded in.
+///
+/// # Returns
+///
+/// * `Ok(image::DynamicImage)` - The decoded image.
+/// * `Err(Box<dyn std::error::Error>)` - An error if the data could not be decoded.
+pub fn load_image_from_bytes_with_format(
+    bytes: &[u8],
+    format: image::ImageFormat,
+) -> Result<image::DynamicImage, Box<dyn std::error::Error>> {
+    let img = image::load_from_memory_with_format(bytes, format)?;
+    Ok(apply_exif_orientation_from_bytes(img, bytes))
+}
+
+/// Rotates an image decoded from `bytes` according to the EXIF orientation in them.
+fn apply_exif_orientation_from_bytes(
+    img: image::DynamicImage,
+    bytes: &[u8],
+) -> image::DynamicImage {
+    let orientation = read_exif_orientation_from(&mut std::io::Cursor::new(bytes)).unwrap_or(1);
+    apply_orientation(img, orientation)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::io::Cursor;
+
+    /// Encodes a small solid-color image in the given format
+    fn encode_image(format: image::ImageFormat) -> Vec<u8> {
+        let img = image::RgbImage::from_pixel(8, 6, image::Rgb([180, 140, 120]));
+        let mut bytes = Vec::new();
+        image::DynamicImage::ImageRgb8(img)
+            .write_to(&mut Cursor::new(&mut bytes), format)
+            .expect("Failed to encode test image");
+        bytes
+    }
+
+    /// Inserts an EXIF segment holding only an `Orientation` tag after the JPEG SOI marker
+    fn with_exif_orientation(jpeg: &[u8], orientation: u16) -> Vec<u8> {
+        let mut tiff = b"MM\x00\x2a\x00\x00\x00\x08".to_vec();
+        tiff.extend_from_slice(&1u16.to_be_bytes()); // one IFD entry
+        tiff.extend_from_slice(&0x0112u16.to_be_bytes()); // Orientation
+        tiff.extend_from_slice(&3u16.to_be_bytes()); // SHORT
+        tiff.extend_from_slice(&1u32.to_be_bytes()); // count
+        tiff.extend_from_slice(&orientation.to_be_bytes());
+        tiff.extend_from_slice(&[0, 0]); // value padding
+        tiff.extend_from_slice(&0u32.to_be_bytes()); // no next IFD
+
+        let mut segment = b"Exif\x00\x00".to_vec();
+        segment.extend_from_slice(&tiff);
+
+        let mut output = jpeg[..2].to_vec();
+        output.extend_from_slice(&[0xFF, 0xE1]);
+        output.extend_from_slice(&((segment.len() + 2) as u16).to_be_bytes());
+        output.extend_from_slice(&segment);
+        output.extend_from_slice(&jpeg[2..]);
+        output
+    }
+
+    #[test]
+    fn test_load_image_respecting_exif_orientation() {
+        let jpeg = encode_image(image::ImageFormat::Jpeg);
+        let dir = tempfile::tempdir().unwrap();
+
+        for (orientation, expected) in [(1, (8, 6)), (3, (8, 6)), (6, (6, 8)), (8, (6, 8))] {
+            let bytes = with_exif_orientation(&jpeg, orientation);
+            let path = dir.path().join(format!("orientation_{}.jpg", orientation));
+            std::fs::write(&path, &bytes).unwrap();
+
+            let path = path.to_str().unwrap();
+            assert_eq!(read_exif_orientation(path), Some(orientation as u32));
+            let img = load_image(path).expect("Failed to load image");
+            assert_eq!((img.width(), img.height()), expected);
+
+            // Uploads are rotated the same way as files
+            let from_bytes = load_image_from_bytes(&bytes).expect("Failed to decode image");
+            assert_eq!(from_bytes, img);
+            let bounded = load_image_from_bytes_bounded(&bytes, DEFAULT_MAX_PIXELS).unwrap();
+            assert_eq!((bounded.width(), bounded.height()), expected);
+        }
+    }
+
+    #[test]
+    fn test_apply_orientation_rotates_pixels() {
+        let mut img = image::RgbImage::new(2, 1);
+        img.put_pixel(0, 0, image::Rgb([255, 0, 0]));
+        let img = image::DynamicImage::ImageRgb8(img);
+
+        // Orientation 6 is stored rotated 90 degrees counter-clockwise,
+        // so the left pixel ends up at the top after correcting it
+        let upright = apply_orientation(img.clone(), 6).to_rgb8();
+        assert_eq!(upright.dimensions(), (1, 2));
+        assert_eq!(upright.get_pixel(0, 0), &image::Rgb([255, 0, 0]));
+
+        let mirrored = apply_orientation(img, 2).to_rgb8();
+        assert_eq!(mirrored.get_pixel(1, 0), &image::Rgb([255, 0, 0]));
+    }
+
+    #[cfg(not(feature = "heif"))]
+    #[test]
+    fn test_load_heic_without_feature() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("photo.heic");
+        std::fs::write(&path, b"not decoded anyway").unwrap();
+
+        let err = load_image(path.to_str().unwrap()).unwrap_err();
+        assert!(err.to_string().contains("HEIC support not enabled"));
+    }
+
+    /// Encodes a small HEIC fixture with libheif and decodes it back
+    #[cfg(feature = "heif")]
+    #[test]
+    fn test_load_heic_fixture() {
+        use libheif_rs::{
+            Channel, ColorSpace, CompressionFormat, EncoderQuality, HeifContext, Image, LibHeif,
+            RgbChroma,
+        };
+
+        let (width, height) = (16, 12);
+        let mut fixture = Image::new(width, height, ColorSpace::Rgb(RgbChroma::C444)).unwrap();
+        for channel in [Channel::R, Channel::G, Channel::B] {
+            fixture.create_plane(channel, width, height, 8).unwrap();
+        }
+        let planes = fixture.planes_mut();
+        for plane in [planes.r, planes.g, planes.b].into_iter().flatten() {
+            plane.data.fill(128);
+        }
+
+        let lib_heif = LibHeif::new();
+        let mut context = HeifContext::new().unwrap();
+        let mut encoder = lib_heif
+            .encoder_for_format(CompressionFormat::Hevc)
+            .unwrap();
+        encoder.set_quality(EncoderQuality::LossLess).unwrap();
+        context.encode_image(&fixture, &mut encoder, None).unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("fixture.heic");
+        context.write_to_file(path.to_str().unwrap()).unwrap();
+
+        let img = load_image(path.to_str().unwrap()).expect("Failed to load HEIC image");
+        assert_eq!((img.width(), img.height()), (width, height));
+    }
+
+    #[test]
+    fn test_load_image_bounded_accepts_normal_image() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("small.png");
+        std::fs::write(&path, encode_image(image::ImageFormat::Png)).unwrap();
+
+        let img = load_image_bounded(path.to_str().unwrap(), DEFAULT_MAX_PIXELS)
+            .expect("Failed to load image");
+        assert_eq!((img.width(), img.height()), (8, 6));
+
+        // The limit is inclusive
+        assert!(load_image_bounded(path.to_str().unwrap(), 48).is_ok());
+        assert!(load_image_bounded(path.to_str().unwrap(), 47).is_err());
+    }
+
+    #[test]
+    fn test_load_image_bounded_ignores_wrong_extension() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("photo");
+        std::fs::write(&path, encode_image(image::ImageFormat::Jpeg)).unwrap();
+
+        let img = load_image_bounded(path.to_str().unwrap(), DEFAULT_MAX_PIXELS)
+            .expect("Failed to load image");
+        assert_eq!((img.width(), img.height()), (8, 6));
+        assert!(load_image_bounded(path.to_str().unwrap(), 47).is_err());
+    }
+
+    /// A BMP header claiming 20000x20000 pixels with no pixel data behind it;
+    /// decoding it fully would fail differently, so a pixel-limit error proves
+    /// the header check ran first
+    fn oversized_bmp_header() -> Vec<u8> {
+        let (width, height) = (20_000i32, 20_000i32);
+        let mut bmp = b"BM".to_vec();
+        bmp.extend_from_slice(&54u32.to_le_bytes()); // file size
+        bmp.extend_from_slice(&0u32.to_le_bytes()); // reserved
+        bmp.extend_from_slice(&54u32.to_le_bytes()); // pixel data offset
+        bmp.extend_from_slice(&40u32.to_le_bytes()); // info header size
+        bmp.extend_from_slice(&width.to_le_bytes());
+        bmp.extend_from_slice(&height.to_le_bytes());
+        bmp.extend_from_slice(&1u16.to_le_bytes()); // planes
+        bmp.extend_from_slice(&24u16.to_le_bytes()); // bits per pixel
+        bmp.extend_from_slice(&[0; 24]); // no compression, sizes and palette
+        bmp
+    }
+
+    #[test]
+    fn test_load_image_bounded_rejects_oversized_header() {
+        let bmp = oversized_bmp_header();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("huge.bmp");
+        std::fs::write(&path, &bmp).unwrap();
+
+        let err = load_image_bounded(path.to_str().unwrap(), DEFAULT_MAX_PIXELS).unwrap_err();
+        assert!(err.to_string().contains("exceeds the limit"));
+    }
+
+    #[test]
+    fn test_supported_extensions() {
+        assert!(supported_extensions().contains(&"jpg"));
+        assert!(supported_extensions().contains(&"png"));
+        assert!(!supported_extensions().contains(&"txt"));
+        assert_eq!(
+            supported_extensions().contains(&"heic"),
+            cfg!(feature = "heif")
+        );
+        assert!(IMAGE_CRATE_EXTENSIONS
+            .iter()
+            .all(|ext| supported_extensions().contains(ext)));
+    }
+
+    #[test]
+    fn test_is_supported_image() {
+        let dir = tempfile::tempdir().unwrap();
+        let path_of = |name: &str| dir.path().join(name).to_str().unwrap().to_string();
+
+        std::fs::write(path_of("photo.jpg"), encode_image(image::ImageFormat::Jpeg)).unwrap();
+        std::fs::write(path_of("photo.PNG"), encode_image(image::ImageFormat::Png)).unwrap();
+        std::fs::write(path_of("photo.tga"), encode_image(image::ImageFormat::Tga)).unwrap();
+        std::fs::write(path_of("notes.txt"), b"just some text").unwrap();
+        std::fs::write(path_of("fake.jpg"), b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n").unwrap();
+        std::fs::write(path_of("text.jpg"), b"just some text in disguise").unwrap();
+        std::fs::write(path_of("text.tga.txt"), b"").unwrap();
+        std::fs::write(path_of("photo.ppm"), encode_image(image::ImageFormat::Pnm)).unwrap();
+        std::fs::write(path_of("photo.qoi"), encode_image(image::ImageFormat::Qoi)).unwrap();
+
+        assert!(is_supported_image(&path_of("photo.jpg")));
+        assert!(is_supported_image(&path_of("photo.PNG")));
+        assert!(is_supported_image(&path_of("photo.tga")));
+        assert!(!is_supported_image(&path_of("notes.txt")));
+        assert!(!is_supported_image(&path_of("fake.jpg")));
+        assert!(!is_supported_image(&path_of("text.jpg")));
+        assert!(!is_supported_image(&path_of("text.tga.txt")));
+        assert!(is_supported_image(&path_of("photo.ppm")));
+        assert!(is_supported_image(&path_of("photo.qoi")));
+        assert!(!is_supported_image(&path_of("missing.jpg")));
+    }
+
+    #[test]
+    fn test_load_image_ignores_wrong_extension() {
+        let dir = tempfile::tempdir().unwrap();
+        let extensionless = dir.path().join("photo");
+        let misnamed = dir.path().join("photo.png");
+        std::fs::write(&extensionless, encode_image(image::ImageFormat::Jpeg)).unwrap();
+        std::fs::write(&misnamed, encode_image(image::ImageFormat::Jpeg)).unwrap();
+
+        for path in [&extensionless, &misnamed] {
+            let img = load_image(path.to_str().unwrap()).expect("Failed to load image");
+            assert_eq!((img.width(), img.height()), (8, 6));
+        }
+    }
 
     #[test]
     fn test_load_image_failure() {
         let result = load_image("non_existent_image.png");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_load_image_from_bytes() {
+        let bytes = encode_image(image::ImageFormat::Png);
+        let img = load_image_from_bytes(&bytes).expect("Failed to decode image");
+        assert_eq!((img.width(), img.height()), (8, 6));
+    }
+
+    #[test]
+    fn test_load_image_from_bytes_bounded() {
+        let png = encode_image(image::ImageFormat::Png);
+        let img = load_image_from_bytes_bounded(&png, 48).expect("Failed to decode image");
+        assert_eq!((img.width(), img.height()), (8, 6));
+        assert!(load_image_from_bytes_bounded(&png, 47).is_err());
+
+        let err =
+            load_image_from_bytes_bounded(&oversized_bmp_header(), DEFAULT_MAX_PIXELS).unwrap_err();
+        assert!(err.to_string().contains("exceeds the limit"));
+        assert!(load_image_from_bytes_bounded(b"not an image", DEFAULT_MAX_PIXELS).is_err());
+    }
+
+    #[test]
+    fn test_load_image_from_bytes_with_format() {
+        let bytes = encode_image(image::ImageFormat::Bmp);
+        let img = load_image_from_bytes_with_format(&bytes, image::ImageFormat::Bmp)
+            .expect("Failed to decode image");
+        assert_eq!((img.width(), img.height()), (8, 6));
+    }
+
+    #[test]
+    fn test_load_image_from_bytes_invalid() {
+        assert!(load_image_from_bytes(b"not an image").is_err());
+    }
 }
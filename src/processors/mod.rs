@@ -1,2 +1,10 @@
+pub mod align;
+pub mod annotate;
 pub mod face_detector;
 pub mod image_loader;
+pub mod pipeline;
+pub mod preprocess;
+pub mod quality;
+pub mod thumbnail;
+#[cfg(feature = "opencv")]
+pub mod video;
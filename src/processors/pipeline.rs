@@ -0,0 +1,198 @@
+use crate::models::detection::Detection;
+use crate::processors::annotate::draw_detections;
+use crate::processors::face_detector::{detect_faces_with_preprocess, non_max_suppression};
+use crate::processors::image_loader::{load_image_bounded, DEFAULT_MAX_PIXELS};
+use crate::processors::preprocess::PreprocessOpts;
+use image::DynamicImage;
+
+/// A configurable image-processing chain: load, preprocess, detect, NMS, filter and draw.
+///
+/// Steps are added with builder methods and run in a fixed order by `run`.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessingPipeline {
+    preprocess: PreprocessOpts,
+    nms_iou_threshold: Option<f32>,
+    min_confidence: Option<f32>,
+    max_pixels: Option<u64>,
+    draw: bool,
+}
+
+impl ProcessingPipeline {
+    /// Creates a pipeline that only loads the image and detects faces.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Preprocesses the image with `opts` before detection.
+    pub fn preprocess(mut self, opts: PreprocessOpts) -> Self {
+        self.preprocess = opts;
+        self
+    }
+
+    /// Runs an extra non-maximum suppression pass with `iou_threshold`.
+    ///
+    /// Detection already merges boxes at the default IoU threshold, so this
+    /// step only has an effect with a stricter (lower) threshold.
+    pub fn nms(mut self, iou_threshold: f32) -> Self {
+        self.nms_iou_threshold = Some(iou_threshold);
+        self
+    }
+
+    /// Drops detections whose confidence is below `min_confidence`.
+    pub fn min_confidence(mut self, min_confidence: f32) -> Self {
+        self.min_confidence = Some(min_confidence);
+        self
+    }
+
+    /// Rejects images with more than `max_pixels` pixels instead of the
+    /// default `DEFAULT_MAX_PIXELS`.
+    pub fn max_pixels(mut self, max_pixels: u64) -> Self {
+        self.max_pixels = Some(max_pixels);
+        self
+    }
+
+    /// Draws the final detections onto the image returned by `run`.
+    pub fn draw(mut self) -> Self {
+        self.draw = true;
+        self
+    }
+
+    /// Runs the pipeline on an image file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A string slice that holds the path to the image file.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(DynamicImage, Vec<Detection>), Box<dyn std::error::Error>>` - The loaded (or annotated) image and the detections that passed every step.
+    pub fn run(
+        &self,
+        path: &str,
+    ) -> Result<(DynamicImage, Vec<Detection>), Box<dyn std::error::Error>> {
+        let image = load_image_bounded(path, self.max_pixels.unwrap_or(DEFAULT_MAX_PIXELS))?;
+        let detections = self.run_on_image(&image);
+
+        if self.draw {
+            return Ok((draw_detections(&image, &detections), detections));
+        }
+
+        Ok((image, detections))
+    }
+
+    /// Runs the detection steps of the pipeline on an already loaded image.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - A reference to a `image::DynamicImage`.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<Detection>` - The detections that passed every step.
+    pub fn run_on_image(&self, image: &DynamicImage) -> Vec<Detection> {
+        let mut detections = detect_faces_with_preprocess(image, &self.preprocess);
+
+        if let Some(iou_threshold) = self.nms_iou_threshold {
+            detections = non_max_suppression(detections, iou_threshold);
+        }
+
+        if let Some(min_confidence) = self.min_confidence {
+            detections.retain(|detection| detection.confidence >= min_confidence);
+        }
+
+        detections
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgb, RgbImage};
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_pipeline_preprocess_and_min_confidence() {
+        let dir = tempdir().expect("Failed to create temporary directory");
+        let file_path = dir.path().join("face.png");
+
+        // Skin-like square on a blue background
+        let mut img_buffer = RgbImage::new(200, 200);
+        for (x, y, pixel) in img_buffer.enumerate_pixels_mut() {
+            if (50..150).contains(&x) && (50..150).contains(&y) {
+                *pixel = Rgb([180, 140, 120]);
+            } else {
+                *pixel = Rgb([0, 0, 255]);
+            }
+        }
+        img_buffer
+            .save(&file_path)
+            .expect("Failed to save test image");
+
+        let pipeline = ProcessingPipeline::new()
+            .preprocess(PreprocessOpts {
+                blur_sigma: Some(1.0),
+                equalize: false,
+            })
+            .min_confidence(0.9);
+        let (image, detections) = pipeline
+            .run(file_path.to_str().unwrap())
+            .expect("Pipeline failed");
+
+        assert_eq!((image.width(), image.height()), (200, 200));
+        assert!(!detections.is_empty());
+        assert!(detections.iter().all(|d| d.confidence >= 0.9));
+
+        // The unfiltered pipeline keeps the weaker detections too
+        let unfiltered = ProcessingPipeline::new().run_on_image(&image);
+        assert!(unfiltered.len() > detections.len());
+    }
+
+    #[test]
+    fn test_pipeline_draw() {
+        let dir = tempdir().expect("Failed to create temporary directory");
+        let file_path = dir.path().join("face.png");
+
+        let mut img_buffer = RgbImage::new(200, 200);
+        for (x, y, pixel) in img_buffer.enumerate_pixels_mut() {
+            if (50..150).contains(&x) && (50..150).contains(&y) {
+                *pixel = Rgb([180, 140, 120]);
+            } else {
+                *pixel = Rgb([0, 0, 255]);
+            }
+        }
+        img_buffer
+            .save(&file_path)
+            .expect("Failed to save test image");
+
+        let (annotated, detections) = ProcessingPipeline::new()
+            .draw()
+            .run(file_path.to_str().unwrap())
+            .expect("Pipeline failed");
+
+        assert!(!detections.is_empty());
+        assert_ne!(annotated.to_rgb8(), img_buffer);
+    }
+
+    #[test]
+    fn test_pipeline_max_pixels() {
+        let dir = tempdir().expect("Failed to create temporary directory");
+        let file_path = dir.path().join("face.png");
+        RgbImage::from_pixel(20, 10, Rgb([180, 140, 120]))
+            .save(&file_path)
+            .expect("Failed to save test image");
+        let path = file_path.to_str().unwrap();
+
+        assert!(ProcessingPipeline::new().max_pixels(200).run(path).is_ok());
+        let err = ProcessingPipeline::new()
+            .max_pixels(199)
+            .run(path)
+            .unwrap_err();
+        assert!(err.to_string().contains("exceeds the limit"));
+    }
+
+    #[test]
+    fn test_pipeline_invalid_path() {
+        let result = ProcessingPipeline::new().run("invalid_path.png");
+        assert!(result.is_err());
+    }
+}
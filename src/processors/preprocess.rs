@@ -0,0 +1,224 @@
+use image::{DynamicImage, RgbImage};
+
+/// Options controlling the preprocessing applied before skin analysis.
+///
+/// The default performs no preprocessing, so detection behaves exactly as it
+/// does on the raw image.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PreprocessOpts {
+    /// Sigma of the Gaussian blur used for denoising, or `None` to skip it.
+    pub blur_sigma: Option<f32>,
+
+    /// Whether to equalize the histogram of each RGB channel.
+    pub equalize: bool,
+}
+
+/// Applies the preprocessing steps selected in `opts` to an RGB image.
+///
+/// # Arguments
+///
+/// * `image` - A reference to the RGB image to preprocess.
+/// * `opts` - The preprocessing options.
+///
+/// # Returns
+///
+/// * `RgbImage` - The preprocessed image (a copy of the input if no step is enabled).
+pub fn preprocess(image: &RgbImage, opts: &PreprocessOpts) -> RgbImage {
+    let mut output = match opts.blur_sigma {
+        Some(sigma) if sigma > 0.0 => image::imageops::blur(image, sigma),
+        _ => image.clone(),
+    };
+
+    if opts.equalize {
+        output = equalize_histogram(&output);
+    }
+
+    output
+}
+
+/// Equalizes the histogram of each channel of an RGB image independently.
+///
+/// # Arguments
+///
+/// * `image` - A reference to the RGB image to equalize.
+///
+/// # Returns
+///
+/// * `RgbImage` - The equalized image.
+pub fn equalize_histogram(image: &RgbImage) -> RgbImage {
+    let total = (image.width() * image.height()) as u64;
+    if total == 0 {
+        return image.clone();
+    }
+
+    // Build a lookup table per channel from its cumulative distribution
+    let mut luts = [[0u8; 256]; 3];
+    for (channel, lut) in luts.iter_mut().enumerate() {
+        let mut histogram = [0u64; 256];
+        for pixel in image.pixels() {
+            histogram[pixel[channel] as usize] += 1;
+        }
+
+        let cdf_min = histogram
+            .iter()
+            .copied()
+            .find(|&count| count > 0)
+            .unwrap_or(0);
+        let mut cumulative = 0u64;
+        for (value, count) in histogram.iter().enumerate() {
+            cumulative += count;
+            lut[value] = if total == cdf_min {
+                value as u8
+            } else {
+                ((cumulative.saturating_sub(cdf_min) * 255) / (total - cdf_min)) as u8
+            };
+        }
+    }
+
+    let mut output = image.clone();
+    for pixel in output.pixels_mut() {
+        for (channel, lut) in luts.iter().enumerate() {
+            pixel[channel] = lut[pixel[channel] as usize];
+        }
+    }
+
+    output
+}
+
+/// Stretches the intensities of an image to cover the full `0..=255` range.
+///
+/// The same linear mapping is applied to all three channels, so hues (and
+/// with them skin tones) are preserved while underexposed photos are brightened.
+///
+/// # Arguments
+///
+/// * `image` - A reference to the image to adjust.
+///
+/// # Returns
+///
+/// * `DynamicImage` - An RGB copy of the image with its contrast stretched.
+pub fn auto_contrast(image: &DynamicImage) -> DynamicImage {
+    let mut rgb = image.to_rgb8();
+
+    let (min, max) = rgb
+        .pixels()
+        .flat_map(|pixel| pixel.0)
+        .fold((u8::MAX, u8::MIN), |(min, max), v| (min.min(v), max.max(v)));
+    if max <= min {
+        return DynamicImage::ImageRgb8(rgb);
+    }
+
+    let range = (max - min) as u32;
+    for pixel in rgb.pixels_mut() {
+        for value in pixel.0.iter_mut() {
+            *value = ((*value - min) as u32 * 255 / range) as u8;
+        }
+    }
+
+    DynamicImage::ImageRgb8(rgb)
+}
+
+/// Applies gamma correction to an image.
+///
+/// Each channel value `v` becomes `255 * (v / 255)^(1 / gamma)`, so values
+/// above 1.0 brighten the image and values below 1.0 darken it.
+///
+/// # Arguments
+///
+/// * `image` - A reference to the image to adjust.
+/// * `gamma` - The gamma value; non-positive values leave the image unchanged.
+///
+/// # Returns
+///
+/// * `DynamicImage` - An RGB copy of the gamma-corrected image.
+pub fn gamma_correct(image: &DynamicImage, gamma: f32) -> DynamicImage {
+    let mut rgb = image.to_rgb8();
+    if gamma <= 0.0 {
+        return DynamicImage::ImageRgb8(rgb);
+    }
+
+    let mut lut = [0u8; 256];
+    for (value, entry) in lut.iter_mut().enumerate() {
+        *entry = (255.0 * (value as f32 / 255.0).powf(1.0 / gamma)).round() as u8;
+    }
+    for pixel in rgb.pixels_mut() {
+        for value in pixel.0.iter_mut() {
+            *value = lut[*value as usize];
+        }
+    }
+
+    DynamicImage::ImageRgb8(rgb)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processors::face_detector::detect_faces;
+    use image::Rgb;
+
+    /// Dark skin-toned square on a dark blue background, like an underexposed photo
+    fn dark_skin_image() -> DynamicImage {
+        let mut img = RgbImage::new(200, 200);
+        for (x, y, pixel) in img.enumerate_pixels_mut() {
+            *pixel = if (50..150).contains(&x) && (50..150).contains(&y) {
+                Rgb([72, 56, 48])
+            } else {
+                Rgb([0, 0, 40])
+            };
+        }
+        DynamicImage::ImageRgb8(img)
+    }
+
+    #[test]
+    fn test_preprocess_default_is_identity() {
+        let mut img = RgbImage::new(10, 10);
+        for (x, y, pixel) in img.enumerate_pixels_mut() {
+            *pixel = Rgb([(x * 20) as u8, (y * 20) as u8, 100]);
+        }
+
+        let output = preprocess(&img, &PreprocessOpts::default());
+        assert_eq!(output, img);
+    }
+
+    #[test]
+    fn test_equalize_histogram_stretches_range() {
+        // Low-contrast image with values between 100 and 109
+        let mut img = RgbImage::new(10, 10);
+        for (x, _, pixel) in img.enumerate_pixels_mut() {
+            let v = 100 + x as u8;
+            *pixel = Rgb([v, v, v]);
+        }
+
+        let output = equalize_histogram(&img);
+        let min = output.pixels().map(|p| p[0]).min().unwrap();
+        let max = output.pixels().map(|p| p[0]).max().unwrap();
+        assert_eq!(min, 0);
+        assert_eq!(max, 255);
+    }
+
+    #[test]
+    fn test_auto_contrast_makes_dark_skin_detectable() {
+        let dark = dark_skin_image();
+        assert!(detect_faces(&dark).is_empty());
+
+        let adjusted = auto_contrast(&dark);
+        assert_eq!(
+            adjusted.to_rgb8().get_pixel(100, 100),
+            &Rgb([255, 198, 170])
+        );
+        assert!(!detect_faces(&adjusted).is_empty());
+    }
+
+    #[test]
+    fn test_gamma_correct() {
+        let img = DynamicImage::ImageRgb8(RgbImage::from_pixel(4, 4, Rgb([0, 64, 255])));
+
+        assert_eq!(gamma_correct(&img, 1.0).to_rgb8(), img.to_rgb8());
+
+        // End points stay fixed while mid-tones move
+        let brighter = gamma_correct(&img, 2.0).to_rgb8();
+        assert_eq!(brighter.get_pixel(0, 0), &Rgb([0, 128, 255]));
+        let darker = gamma_correct(&img, 0.5).to_rgb8();
+        assert_eq!(darker.get_pixel(0, 0), &Rgb([0, 16, 255]));
+    }
+}
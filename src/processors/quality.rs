@@ -0,0 +1,90 @@
+use image::DynamicImage;
+
+/// Measures the sharpness of an image as the variance of its Laplacian.
+///
+/// Edges produce strong Laplacian responses, so sharp images score high and
+/// blurred or flat images score close to zero. Images smaller than 3x3 score 0.0.
+///
+/// # Arguments
+///
+/// * `image` - A reference to the image to measure.
+///
+/// # Returns
+///
+/// * `f64` - The variance of the 4-neighbour Laplacian over the grayscale image.
+pub fn blur_score(image: &DynamicImage) -> f64 {
+    let gray = image.to_luma8();
+    let (width, height) = gray.dimensions();
+    if width < 3 || height < 3 {
+        return 0.0;
+    }
+
+    let pixel = |x: u32, y: u32| gray.get_pixel(x, y)[0] as f64;
+    let mut sum = 0.0;
+    let mut sum_sq = 0.0;
+    for y in 1..height - 1 {
+        for x in 1..width - 1 {
+            let laplacian = pixel(x - 1, y) + pixel(x + 1, y) + pixel(x, y - 1) + pixel(x, y + 1)
+                - 4.0 * pixel(x, y);
+            sum += laplacian;
+            sum_sq += laplacian * laplacian;
+        }
+    }
+
+    let count = ((width - 2) * (height - 2)) as f64;
+    let mean = sum / count;
+    sum_sq / count - mean * mean
+}
+
+/// Checks whether an image is sharp enough to be worth recognizing.
+///
+/// # Arguments
+///
+/// * `image` - A reference to the image to check.
+/// * `threshold` - The minimum `blur_score` of a sharp image.
+///
+/// # Returns
+///
+/// * `bool` - True if the blur score is at least `threshold`.
+pub fn is_sharp(image: &DynamicImage, threshold: f64) -> bool {
+    blur_score(image) >= threshold
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GrayImage, Luma};
+
+    fn checkerboard(size: u32, cell: u32) -> DynamicImage {
+        DynamicImage::ImageLuma8(GrayImage::from_fn(size, size, |x, y| {
+            if (x / cell + y / cell).is_multiple_of(2) {
+                Luma([0])
+            } else {
+                Luma([255])
+            }
+        }))
+    }
+
+    #[test]
+    fn test_checkerboard_is_sharper_than_blurred() {
+        let sharp = checkerboard(64, 4);
+        let blurred = sharp.blur(4.0);
+
+        let sharp_score = blur_score(&sharp);
+        let blurred_score = blur_score(&blurred);
+        assert!(sharp_score > 10.0 * blurred_score);
+        assert!(is_sharp(&sharp, 100.0));
+        assert!(!is_sharp(&blurred, 100.0));
+    }
+
+    #[test]
+    fn test_uniform_image_scores_zero() {
+        let flat = DynamicImage::ImageLuma8(GrayImage::from_pixel(32, 32, Luma([128])));
+        assert_eq!(blur_score(&flat), 0.0);
+    }
+
+    #[test]
+    fn test_tiny_image_scores_zero() {
+        assert_eq!(blur_score(&DynamicImage::new_luma8(2, 2)), 0.0);
+    }
+}
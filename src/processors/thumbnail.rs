@@ -0,0 +1,50 @@
+use image::imageops::FilterType;
+use image::DynamicImage;
+
+/// Resizes an image so that its longest side is at most `max_dim` pixels.
+///
+/// The aspect ratio is preserved, and images already within `max_dim` are
+/// returned unchanged rather than upscaled.
+///
+/// # Arguments
+///
+/// * `image` - A reference to the image to shrink.
+/// * `max_dim` - The maximum length of the longest side, in pixels.
+///
+/// # Returns
+///
+/// * `DynamicImage` - The thumbnail.
+pub fn make_thumbnail(image: &DynamicImage, max_dim: u32) -> DynamicImage {
+    let max_dim = max_dim.max(1);
+    if image.width().max(image.height()) <= max_dim {
+        return image.clone();
+    }
+
+    image.resize(max_dim, max_dim, FilterType::Triangle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_make_thumbnail_landscape() {
+        let img = DynamicImage::new_rgb8(400, 300);
+        let thumb = make_thumbnail(&img, 100);
+        assert_eq!((thumb.width(), thumb.height()), (100, 75));
+    }
+
+    #[test]
+    fn test_make_thumbnail_portrait() {
+        let img = DynamicImage::new_rgb8(300, 600);
+        let thumb = make_thumbnail(&img, 128);
+        assert_eq!((thumb.width(), thumb.height()), (64, 128));
+    }
+
+    #[test]
+    fn test_make_thumbnail_does_not_upscale() {
+        let img = DynamicImage::new_rgb8(50, 30);
+        let thumb = make_thumbnail(&img, 128);
+        assert_eq!((thumb.width(), thumb.height()), (50, 30));
+    }
+}
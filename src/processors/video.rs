@@ -0,0 +1,156 @@
+use crate::models::detection::Detection;
+use crate::processors::face_detector::detect_faces;
+use image::{DynamicImage, RgbImage};
+use opencv::core::{Mat, CV_8UC3};
+use opencv::prelude::*;
+use opencv::videoio::{VideoCapture, CAP_ANY};
+
+/// Detects faces in every `stride`-th frame of a video file.
+///
+/// Frames are decoded with OpenCV's `VideoCapture`, so any container and
+/// codec supported by the system OpenCV build (e.g. MP4/H.264 through FFmpeg)
+/// can be read. A video without frames yields no results.
+///
+/// # Arguments
+///
+/// * `path` - A string slice that holds the path to the video file.
+/// * `stride` - Run detection on frames `0`, `stride`, `2 * stride`, ...; must be at least 1.
+///
+/// # Returns
+///
+/// * `Result<Vec<(usize, Vec<Detection>)>, Box<dyn std::error::Error>>` - The index and
+///   detections of each processed frame, or an error if the video could not be opened or decoded.
+pub fn process_video(
+    path: &str,
+    stride: usize,
+) -> Result<Vec<(usize, Vec<Detection>)>, Box<dyn std::error::Error>> {
+    if stride == 0 {
+        return Err("Frame stride must be at least 1".into());
+    }
+
+    let mut capture = VideoCapture::from_file(path, CAP_ANY)?;
+    if !capture.is_opened()? {
+        return Err(format!("Could not open video '{}'", path).into());
+    }
+
+    let mut results = Vec::new();
+    let mut frame = Mat::default();
+    let mut index = 0;
+    while capture.read(&mut frame)? {
+        if frame.empty() {
+            break;
+        }
+        if index % stride == 0 {
+            let image = frame_to_image(&frame)?;
+            results.push((index, detect_faces(&image)));
+        }
+        index += 1;
+    }
+
+    log::debug!(
+        "Processed {} of {} frames of {}",
+        results.len(),
+        index,
+        path
+    );
+    Ok(results)
+}
+
+/// Converts an 8-bit BGR OpenCV frame into an RGB image.
+fn frame_to_image(frame: &Mat) -> Result<DynamicImage, Box<dyn std::error::Error>> {
+    if frame.typ() != CV_8UC3 {
+        return Err(format!("Unsupported frame type {}", frame.typ()).into());
+    }
+
+    // Frames from VideoCapture are continuous; clone otherwise so the bytes are contiguous
+    let owned;
+    let frame = if frame.is_continuous() {
+        frame
+    } else {
+        owned = frame.try_clone()?;
+        &owned
+    };
+
+    let size = frame.size()?;
+    let pixels = frame
+        .data_bytes()?
+        .chunks_exact(3)
+        .flat_map(|bgr| [bgr[2], bgr[1], bgr[0]])
+        .collect();
+    let image = RgbImage::from_raw(size.width as u32, size.height as u32, pixels)
+        .ok_or("Frame data does not match its dimensions")?;
+    Ok(DynamicImage::ImageRgb8(image))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_video_rejects_zero_stride() {
+        assert!(process_video("video.mp4", 0).is_err());
+    }
+
+    #[test]
+    fn test_process_video_unreadable_file() {
+        assert!(process_video("no_such_video.mp4", 1).is_err());
+    }
+
+    #[test]
+    fn test_frame_to_image_swaps_channels() {
+        let frame = Mat::new_rows_cols_with_default(
+            2,
+            3,
+            CV_8UC3,
+            opencv::core::Scalar::new(255.0, 0.0, 0.0, 0.0),
+        )
+        .unwrap();
+        let image = frame_to_image(&frame).unwrap().to_rgb8();
+        assert_eq!(image.dimensions(), (3, 2));
+        assert!(image.pixels().all(|pixel| pixel.0 == [0, 0, 255]));
+    }
+
+    #[test]
+    fn test_process_video_generated_clip() {
+        use opencv::core::{Scalar, Size};
+        use opencv::videoio::VideoWriter;
+
+        // Motion JPEG in AVI is encoded and decoded by OpenCV itself, without FFmpeg
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("clip.avi");
+        let path = path.to_str().unwrap();
+        let fourcc = VideoWriter::fourcc('M', 'J', 'P', 'G').unwrap();
+        let mut writer = VideoWriter::new(path, fourcc, 10.0, Size::new(120, 80), true).unwrap();
+        assert!(writer.is_opened().unwrap());
+
+        // Skin-colored frames, in BGR order
+        let frame = Mat::new_rows_cols_with_default(
+            80,
+            120,
+            CV_8UC3,
+            Scalar::new(120.0, 140.0, 180.0, 0.0),
+        )
+        .unwrap();
+        for _ in 0..7 {
+            writer.write(&frame).unwrap();
+        }
+        writer.release().unwrap();
+
+        let results = process_video(path, 3).expect("Failed to process video");
+        let indices: Vec<usize> = results.iter().map(|(index, _)| *index).collect();
+        assert_eq!(indices, [0, 3, 6]);
+        assert!(results.iter().all(|(_, detections)| !detections.is_empty()));
+    }
+
+    /// Needs OpenCV built with a video backend; pass a clip in `FR_TEST_VIDEO`:
+    ///
+    /// `FR_TEST_VIDEO=clip.mp4 cargo test --features opencv -- --ignored`
+    #[test]
+    #[ignore]
+    fn test_process_video_file() {
+        let path = std::env::var("FR_TEST_VIDEO").expect("FR_TEST_VIDEO is not set");
+        let results = process_video(&path, 5).expect("Failed to process video");
+        assert!(!results.is_empty());
+        assert!(results.iter().all(|(index, _)| index % 5 == 0));
+    }
+}
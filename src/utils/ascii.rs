@@ -0,0 +1,112 @@
+use crate::models::bounding_box::BoundingBox;
+use crate::models::detection::Detection;
+
+/// Renders detections as a downsampled ASCII grid for terminal debugging.
+///
+/// Each detection is drawn as a box outline (`+` corners, `-` and `|` edges)
+/// over a background of `.` cells. Each row of the grid ends with a newline.
+///
+/// # Arguments
+///
+/// * `width` - The width of the source image in pixels.
+/// * `height` - The height of the source image in pixels.
+/// * `dets` - The detections to draw.
+/// * `cols` - The number of columns in the ASCII grid.
+/// * `rows` - The number of rows in the ASCII grid.
+///
+/// # Returns
+///
+/// * `String` - The rendered grid, or an empty string if any dimension is zero.
+pub fn ascii_overlay(width: u32, height: u32, dets: &[Detection], cols: u32, rows: u32) -> String {
+    if width == 0 || height == 0 || cols == 0 || rows == 0 {
+        return String::new();
+    }
+
+    let mut grid = vec![vec!['.'; cols as usize]; rows as usize];
+
+    for detection in dets {
+        let BoundingBox {
+            x,
+            y,
+            width: w,
+            height: h,
+        } = detection.bounding_box;
+        if w == 0 || h == 0 || x >= width || y >= height {
+            continue;
+        }
+
+        // Map the box corners onto grid cells
+        let right = x.saturating_add(w.saturating_sub(1)).min(width - 1);
+        let bottom = y.saturating_add(h.saturating_sub(1)).min(height - 1);
+        let left_col = (x as u64 * cols as u64 / width as u64) as usize;
+        let right_col = (right as u64 * cols as u64 / width as u64) as usize;
+        let top_row = (y as u64 * rows as u64 / height as u64) as usize;
+        let bottom_row = (bottom as u64 * rows as u64 / height as u64) as usize;
+
+        for row in [top_row, bottom_row] {
+            grid[row][left_col..=right_col].fill('-');
+        }
+        for row in grid.iter_mut().take(bottom_row + 1).skip(top_row) {
+            row[left_col] = '|';
+            row[right_col] = '|';
+        }
+        for (row, col) in [
+            (top_row, left_col),
+            (top_row, right_col),
+            (bottom_row, left_col),
+            (bottom_row, right_col),
+        ] {
+            grid[row][col] = '+';
+        }
+    }
+
+    let mut output = String::with_capacity(((cols + 1) * rows) as usize);
+    for row in grid {
+        output.extend(row);
+        output.push('\n');
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_overlay_centered_detection() {
+        let detection = Detection {
+            confidence: 0.9,
+            bounding_box: BoundingBox::new(25, 25, 50, 50),
+        };
+
+        let overlay = ascii_overlay(100, 100, &[detection], 10, 10);
+        let lines: Vec<&str> = overlay.lines().collect();
+        assert_eq!(lines.len(), 10);
+
+        // Corners land on cells 2 and 7 in both directions
+        assert_eq!(lines[2], "..+----+..");
+        assert_eq!(lines[4], "..|....|..");
+        assert_eq!(lines[7], "..+----+..");
+        assert_eq!(lines[0], "..........");
+        assert_eq!(lines[9], "..........");
+    }
+
+    #[test]
+    fn test_ascii_overlay_empty_dimensions() {
+        assert!(ascii_overlay(0, 100, &[], 10, 10).is_empty());
+        assert!(ascii_overlay(100, 100, &[], 0, 10).is_empty());
+    }
+
+    #[test]
+    fn test_ascii_overlay_huge_box() {
+        let detection = Detection {
+            confidence: 0.9,
+            bounding_box: BoundingBox::new(50, 50, u32::MAX, u32::MAX),
+        };
+        let overlay = ascii_overlay(100, 100, &[detection], 10, 10);
+        let lines: Vec<&str> = overlay.lines().collect();
+        assert_eq!(lines.len(), 10);
+        assert_eq!(lines[5], ".....+---+");
+        assert_eq!(lines[9], ".....+---+");
+    }
+}
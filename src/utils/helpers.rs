@@ -1,3 +1,5 @@
+use crate::models::bounding_box::BoundingBox;
+
 /// A simple helper function to calculate the area of a rectangle.
 ///
 /// # Arguments
@@ -8,7 +10,7 @@
 ///
 /// * `u32` - The area of the rectangle.
 pub fn calculate_area(rect: (u32, u32, u32, u32)) -> u32 {
-    rect.2 * rect.3
+    BoundingBox::from(rect).area()
 }
 
 /// A simple helper function to calculate the distance between two points.
@@ -25,6 +27,148 @@ pub fn calculate_distance(p1: (f32, f32), p2: (f32, f32)) -> f32 {
     ((p1.0 - p2.0).powi(2) + (p1.1 - p2.1).powi(2)).sqrt()
 }
 
+/// Distance metric used to compare two face encodings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DistanceMetric {
+    /// Cosine similarity, clamped to 0.0 for negatively correlated vectors.
+    /// Orthogonal encodings score 0.0.
+    #[default]
+    Cosine,
+
+    /// Euclidean distance `d` mapped to `1 / (1 + d)`.
+    /// Orthogonal unit encodings score about 0.41.
+    Euclidean,
+
+    /// Chi-square distance `d = 0.5 * sum((a - b)^2 / (a + b))` mapped to
+    /// `1 / (1 + d)`; suited to normalized histograms.
+    /// Orthogonal unit encodings score 0.5.
+    ChiSquare,
+}
+
+/// Compares two face encodings using cosine similarity.
+///
+/// # Arguments
+///
+/// * `a` - The first face encoding.
+/// * `b` - The second face encoding.
+///
+/// # Returns
+///
+/// * `f32` - The cosine similarity, or 0.0 if the encodings are empty, differ in length or have zero norm.
+pub fn compare_faces(a: &[f32], b: &[f32]) -> f32 {
+    compare_faces_metric(a, b, DistanceMetric::Cosine)
+}
+
+/// Compares two face encodings using the given metric.
+///
+/// # Arguments
+///
+/// * `a` - The first face encoding.
+/// * `b` - The second face encoding.
+/// * `metric` - The metric used for the comparison.
+///
+/// # Returns
+///
+/// * `f32` - A similarity in `0.0..=1.0` where 1.0 means identical, or 0.0 if the encodings are empty or differ in length.
+pub fn compare_faces_metric(a: &[f32], b: &[f32], metric: DistanceMetric) -> f32 {
+    if a.is_empty() || a.len() != b.len() {
+        return 0.0;
+    }
+
+    match metric {
+        DistanceMetric::Cosine => cosine_with_norm(a, norm(a), b),
+        DistanceMetric::Euclidean => {
+            let distance = a
+                .iter()
+                .zip(b)
+                .map(|(x, y)| (x - y).powi(2))
+                .sum::<f32>()
+                .sqrt();
+            1.0 / (1.0 + distance)
+        }
+        DistanceMetric::ChiSquare => {
+            let distance: f32 = a
+                .iter()
+                .zip(b)
+                .filter(|(x, y)| *x + *y != 0.0)
+                .map(|(x, y)| (x - y).powi(2) / (x + y).abs())
+                .sum::<f32>()
+                * 0.5;
+            1.0 / (1.0 + distance)
+        }
+    }
+}
+
+/// Compares two face encodings using cosine similarity, rejecting invalid input.
+///
+/// Unlike `compare_faces`, a length mismatch or an empty encoding is reported
+/// as an error instead of being indistinguishable from dissimilar faces.
+///
+/// # Arguments
+///
+/// * `a` - The first face encoding.
+/// * `b` - The second face encoding.
+///
+/// # Returns
+///
+/// * `Result<f32, Box<dyn std::error::Error>>` - The cosine similarity, or an error describing the invalid input.
+pub fn try_compare_faces(a: &[f32], b: &[f32]) -> Result<f32, Box<dyn std::error::Error>> {
+    if a.is_empty() || b.is_empty() {
+        return Err(format!(
+            "Cannot compare empty face encodings (lengths {} and {})",
+            a.len(),
+            b.len()
+        )
+        .into());
+    }
+    if a.len() != b.len() {
+        return Err(format!("Face encoding lengths differ: {} vs {}", a.len(), b.len()).into());
+    }
+
+    Ok(compare_faces(a, b))
+}
+
+/// Compares one face encoding against many, computing the query norm only once.
+///
+/// # Arguments
+///
+/// * `query` - The face encoding to look up.
+/// * `refs` - The reference encodings to compare against.
+///
+/// # Returns
+///
+/// * `Vec<f32>` - The similarity to each reference, in order, with the same semantics as `compare_faces`.
+pub fn compare_one_to_many(query: &[f32], refs: &[Vec<f32>]) -> Vec<f32> {
+    let query_norm = norm(query);
+
+    refs.iter()
+        .map(|reference| {
+            if query.is_empty() || query.len() != reference.len() {
+                0.0
+            } else {
+                cosine_with_norm(query, query_norm, reference)
+            }
+        })
+        .collect()
+}
+
+/// Euclidean norm of a vector.
+fn norm(v: &[f32]) -> f32 {
+    v.iter().map(|x| x * x).sum::<f32>().sqrt()
+}
+
+/// Cosine similarity of two equal-length vectors given the norm of the first,
+/// clamped to `0.0..=1.0`.
+fn cosine_with_norm(a: &[f32], a_norm: f32, b: &[f32]) -> f32 {
+    let b_norm = norm(b);
+    if a_norm == 0.0 || b_norm == 0.0 {
+        return 0.0;
+    }
+
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    (dot / (a_norm * b_norm)).clamp(0.0, 1.0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,4 +187,73 @@ mod tests {
         let distance = calculate_distance(p1, p2);
         assert_eq!(distance, 5.0);
     }
+
+    #[test]
+    fn test_compare_faces() {
+        assert!((compare_faces(&[1.0, 2.0, 3.0], &[1.0, 2.0, 3.0]) - 1.0).abs() < 1e-6);
+        assert_eq!(compare_faces(&[1.0, 0.0], &[0.0, 1.0]), 0.0);
+        assert_eq!(compare_faces(&[1.0, 2.0], &[1.0, 2.0, 3.0]), 0.0);
+    }
+
+    #[test]
+    fn test_compare_faces_metric_identical() {
+        let v = [0.1, 0.4, 0.0, 0.5];
+        for metric in [
+            DistanceMetric::Cosine,
+            DistanceMetric::Euclidean,
+            DistanceMetric::ChiSquare,
+        ] {
+            assert!((compare_faces_metric(&v, &v, metric) - 1.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_compare_faces_metric_orthogonal() {
+        let a = [1.0, 0.0];
+        let b = [0.0, 1.0];
+        assert_eq!(compare_faces_metric(&a, &b, DistanceMetric::Cosine), 0.0);
+        let euclidean = compare_faces_metric(&a, &b, DistanceMetric::Euclidean);
+        assert!((euclidean - 1.0 / (1.0 + 2f32.sqrt())).abs() < 1e-6);
+        assert_eq!(compare_faces_metric(&a, &b, DistanceMetric::ChiSquare), 0.5);
+    }
+
+    #[test]
+    fn test_try_compare_faces_length_mismatch() {
+        let err = try_compare_faces(&[1.0, 2.0], &[1.0, 2.0, 3.0]).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains('2'));
+        assert!(message.contains('3'));
+    }
+
+    #[test]
+    fn test_try_compare_faces_empty() {
+        assert!(try_compare_faces(&[], &[]).is_err());
+        assert!(try_compare_faces(&[1.0], &[]).is_err());
+    }
+
+    #[test]
+    fn test_try_compare_faces_matches_compare_faces() {
+        let a = [0.2, 0.5, 0.3];
+        let b = [0.1, 0.7, 0.2];
+        let score = try_compare_faces(&a, &b).expect("Comparison failed");
+        assert_eq!(score, compare_faces(&a, &b));
+    }
+
+    #[test]
+    fn test_compare_one_to_many_matches_pairwise() {
+        let query = vec![0.3, 0.1, 0.6, 0.0];
+        let refs = vec![
+            vec![0.3, 0.1, 0.6, 0.0],
+            vec![0.0, 1.0, 0.0, 0.0],
+            vec![0.5, 0.2, 0.2, 0.1],
+            vec![0.0, 0.0, 0.0, 0.0],
+            vec![1.0, 2.0],
+        ];
+
+        let scores = compare_one_to_many(&query, &refs);
+        assert_eq!(scores.len(), refs.len());
+        for (score, reference) in scores.iter().zip(&refs) {
+            assert!((score - compare_faces(&query, reference)).abs() < 1e-6);
+        }
+    }
 }
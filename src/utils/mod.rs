@@ -1 +1,3 @@
+pub mod ascii;
 pub mod helpers;
+pub mod progress;
@@ -0,0 +1,66 @@
+/// Receives progress updates from long-running batch operations.
+///
+/// The library reports through this trait so that embedders can plug in
+/// their own reporting; the CLI uses an `indicatif` progress bar.
+pub trait Progress {
+    /// Called once before the first item with the number of items to process.
+    fn start(&mut self, _total: usize) {}
+
+    /// Called after each item has been processed, successfully or not.
+    fn item_done(&mut self, item: &str);
+
+    /// Called once after the last item.
+    fn finish(&mut self) {}
+}
+
+/// A progress sink that ignores every update.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoProgress;
+
+impl Progress for NoProgress {
+    fn item_done(&mut self, _item: &str) {}
+}
+
+/// A terminal progress bar drawn on stderr.
+#[cfg(feature = "progress")]
+pub struct ProgressBar {
+    bar: indicatif::ProgressBar,
+}
+
+#[cfg(feature = "progress")]
+impl ProgressBar {
+    /// Creates a progress bar; it is drawn once `start` is called.
+    pub fn new() -> Self {
+        ProgressBar {
+            bar: indicatif::ProgressBar::hidden(),
+        }
+    }
+}
+
+#[cfg(feature = "progress")]
+impl Default for ProgressBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "progress")]
+impl Progress for ProgressBar {
+    fn start(&mut self, total: usize) {
+        self.bar = indicatif::ProgressBar::new(total as u64);
+        if let Ok(style) =
+            indicatif::ProgressStyle::with_template("{bar:40} {pos}/{len} [{elapsed}] {msg}")
+        {
+            self.bar.set_style(style);
+        }
+    }
+
+    fn item_done(&mut self, item: &str) {
+        self.bar.set_message(item.to_string());
+        self.bar.inc(1);
+    }
+
+    fn finish(&mut self) {
+        self.bar.finish_and_clear();
+    }
+}
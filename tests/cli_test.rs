@@ -0,0 +1,247 @@
+use std::path::Path;
+use std::process::Command;
+
+/// Writes an image that is entirely skin-colored, so detection always finds a face
+fn write_skin_image(path: &Path) {
+    image::RgbImage::from_pixel(120, 80, image::Rgb([180, 140, 120]))
+        .save(path)
+        .expect("Failed to save test image");
+}
+
+fn run_cli(args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_facial_recognition"))
+        .args(args)
+        .output()
+        .expect("Failed to run the CLI")
+}
+
+#[test]
+fn test_quiet_prints_nothing_on_success() {
+    let dir = tempfile::tempdir().expect("Failed to create temporary directory");
+    let input = dir.path().join("skin.png");
+    let output = dir.path().join("annotated.png");
+    write_skin_image(&input);
+
+    let result = run_cli(&[
+        "--input",
+        input.to_str().unwrap(),
+        "--output",
+        output.to_str().unwrap(),
+        "--database",
+        dir.path().to_str().unwrap(),
+        "--quiet",
+    ]);
+
+    assert_eq!(result.status.code(), Some(0));
+    assert!(result.stdout.is_empty());
+    assert!(output.exists());
+}
+
+#[test]
+fn test_exit_codes() {
+    let dir = tempfile::tempdir().expect("Failed to create temporary directory");
+    let database = dir.path().to_str().unwrap();
+
+    // Database missing
+    let result = run_cli(&[
+        "--input",
+        "tests/test.jpg",
+        "--database",
+        "no_such_database",
+    ]);
+    assert_eq!(result.status.code(), Some(3));
+
+    // Image load failed
+    let missing = dir.path().join("missing.png");
+    let result = run_cli(&[
+        "--input",
+        missing.to_str().unwrap(),
+        "--database",
+        database,
+        "--format",
+        "json",
+    ]);
+    assert_eq!(result.status.code(), Some(4));
+
+    // No faces detected
+    let blue = dir.path().join("blue.png");
+    image::RgbImage::from_pixel(120, 80, image::Rgb([0, 0, 255]))
+        .save(&blue)
+        .expect("Failed to save test image");
+    let result = run_cli(&[
+        "--input",
+        blue.to_str().unwrap(),
+        "--database",
+        database,
+        "--format",
+        "json",
+    ]);
+    assert_eq!(result.status.code(), Some(5));
+}
+
+#[test]
+fn test_exit_codes_in_text_mode() {
+    let dir = tempfile::tempdir().expect("Failed to create temporary directory");
+    let database = dir.path().to_str().unwrap();
+
+    let missing = dir.path().join("missing.jpg");
+    let result = run_cli(&["--input", missing.to_str().unwrap(), "--database", database]);
+    assert_eq!(result.status.code(), Some(4));
+
+    let blue = dir.path().join("blue.png");
+    image::RgbImage::from_pixel(120, 80, image::Rgb([0, 0, 255]))
+        .save(&blue)
+        .expect("Failed to save test image");
+    let result = run_cli(&["--input", blue.to_str().unwrap(), "--database", database]);
+    assert_eq!(result.status.code(), Some(5));
+
+    let skin = dir.path().join("skin.png");
+    write_skin_image(&skin);
+    let result = run_cli(&["--input", skin.to_str().unwrap(), "--database", database]);
+    assert_eq!(result.status.code(), Some(0));
+}
+
+#[test]
+fn test_directory_input() {
+    let dir = tempfile::tempdir().expect("Failed to create temporary directory");
+    let photos = dir.path().join("photos");
+    std::fs::create_dir(&photos).unwrap();
+    write_skin_image(&photos.join("a.png"));
+    write_skin_image(&photos.join("b.png"));
+
+    let result = run_cli(&[
+        "--input",
+        photos.to_str().unwrap(),
+        "--database",
+        dir.path().to_str().unwrap(),
+        "--format",
+        "json",
+        "--no-progress",
+    ]);
+    assert_eq!(result.status.code(), Some(0));
+
+    let report: serde_json::Value = serde_json::from_slice(&result.stdout).unwrap();
+    let images = report["images"].as_array().unwrap();
+    assert_eq!(images.len(), 2);
+    assert!(images[0]["path"].as_str().unwrap().ends_with("a.png"));
+}
+
+#[test]
+fn test_unsupported_input_is_rejected() {
+    let dir = tempfile::tempdir().expect("Failed to create temporary directory");
+
+    // Text is rejected up front, whether or not its name claims it is an image
+    for name in ["notes.txt", "notes.jpg"] {
+        let input = dir.path().join(name);
+        std::fs::write(&input, b"not an image").unwrap();
+
+        let result = run_cli(&[
+            "--input",
+            input.to_str().unwrap(),
+            "--database",
+            dir.path().to_str().unwrap(),
+        ]);
+        assert_eq!(result.status.code(), Some(4));
+        assert!(String::from_utf8_lossy(&result.stderr).contains("Unsupported image format"));
+    }
+}
+
+#[test]
+fn test_thumbnails_flag() {
+    let dir = tempfile::tempdir().expect("Failed to create temporary directory");
+    write_skin_image(&dir.path().join("jane.png"));
+    image::RgbImage::from_pixel(60, 40, image::Rgb([180, 140, 120]))
+        .save(dir.path().join("jane.jpg"))
+        .expect("Failed to save test image");
+
+    let result = run_cli(&[
+        "--input",
+        "tests/test.jpg",
+        "--database",
+        dir.path().to_str().unwrap(),
+        "--format",
+        "json",
+        "--thumbnails",
+    ]);
+
+    let report: serde_json::Value = serde_json::from_slice(&result.stdout).unwrap();
+    assert_eq!(report["thumbnails"].as_array().unwrap().len(), 2);
+    assert!(dir.path().join("thumbs").join("jane.png.png").exists());
+    assert!(dir.path().join("thumbs").join("jane.jpg.png").exists());
+}
+
+#[test]
+fn test_database_from_env() {
+    let dir = tempfile::tempdir().expect("Failed to create temporary directory");
+    write_skin_image(&dir.path().join("jane.png"));
+
+    let result = Command::new(env!("CARGO_BIN_EXE_facial_recognition"))
+        .args(["--input", "tests/test.jpg", "--format", "json"])
+        .env("FR_DATA_DIR", dir.path())
+        .output()
+        .expect("Failed to run the CLI");
+
+    let report: serde_json::Value = serde_json::from_slice(&result.stdout).unwrap();
+    assert_eq!(report["database"], dir.path().to_str().unwrap());
+    assert_eq!(report["persons"][0]["name"], "jane");
+}
+
+/// Inserts an EXIF segment with an `Orientation` of 6 (rotate 90 degrees) after the JPEG SOI marker
+fn rotate_with_exif(jpeg: &[u8]) -> Vec<u8> {
+    let mut segment = b"Exif\x00\x00MM\x00\x2a\x00\x00\x00\x08".to_vec();
+    segment.extend_from_slice(&[0, 1, 0x01, 0x12, 0, 3, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0, 0]);
+
+    let mut output = jpeg[..2].to_vec();
+    output.extend_from_slice(&[0xFF, 0xE1]);
+    output.extend_from_slice(&((segment.len() + 2) as u16).to_be_bytes());
+    output.extend_from_slice(&segment);
+    output.extend_from_slice(&jpeg[2..]);
+    output
+}
+
+#[test]
+fn test_ascii_overlay_uses_exif_rotated_size() {
+    let dir = tempfile::tempdir().expect("Failed to create temporary directory");
+    let plain = dir.path().join("plain.jpg");
+    let rotated = dir.path().join("rotated.jpg");
+    image::RgbImage::from_pixel(160, 80, image::Rgb([180, 140, 120]))
+        .save(&plain)
+        .expect("Failed to save test image");
+    std::fs::write(&rotated, rotate_with_exif(&std::fs::read(&plain).unwrap())).unwrap();
+
+    let overlay_rows = |input: &Path| {
+        let result = run_cli(&[
+            "--input",
+            input.to_str().unwrap(),
+            "--database",
+            dir.path().to_str().unwrap(),
+            "--ascii",
+            "--quiet",
+        ]);
+        assert_eq!(result.status.code(), Some(0));
+        String::from_utf8_lossy(&result.stdout).lines().count()
+    };
+
+    // 80 columns; terminal cells are twice as tall as wide
+    assert_eq!(overlay_rows(&plain), 20);
+    assert_eq!(overlay_rows(&rotated), 80);
+}
+
+#[test]
+fn test_ascii_overlay_rows_are_capped() {
+    let dir = tempfile::tempdir().expect("Failed to create temporary directory");
+    let tall = dir.path().join("tall.png");
+    image::RgbImage::from_pixel(8, 1000, image::Rgb([180, 140, 120]))
+        .save(&tall)
+        .expect("Failed to save test image");
+
+    let result = run_cli(&[
+        "--input",
+        tall.to_str().unwrap(),
+        "--database",
+        dir.path().to_str().unwrap(),
+        "--ascii",
+        "--quiet",
+    ]);
+    assert_eq!(String::from_utf8_lossy(&result.stdout).lines().count(), 200);
+}
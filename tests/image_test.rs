@@ -8,13 +8,10 @@ fn test_process_test_image() {
     // Assert that the result is Ok (no error occurred)
     assert!(result.is_ok());
 
-    // Get the detections
-    let detections = result.unwrap();
-
     // With our new implementation, we might detect some faces
     // Just check that we get a result (vector of detections)
     // The exact number depends on the image content
-    assert!(detections.len() >= 0);
+    let _detections = result.unwrap();
 }
 
 #[test]